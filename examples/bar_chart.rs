@@ -0,0 +1,64 @@
+use std::fmt::Debug;
+
+use pliced::{Cartesian, Chart, LegendPosition, bar_series};
+
+use iced::{Element, Length, Point, Task, widget::container};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    MouseMoved(Point, Cartesian),
+}
+
+#[derive(Debug, Default)]
+struct App {
+    hovered: Option<String>,
+}
+
+const SERVICES: [&str; 5] = ["auth", "billing", "gateway", "search", "storage"];
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        (Self::default(), Task::none())
+    }
+
+    pub fn title(&self) -> String {
+        match &self.hovered {
+            Some(service) => format!("pliced - {service}"),
+            None => "pliced".to_string(),
+        }
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {
+            Message::MouseMoved(position, cartesian) => {
+                self.hovered = cartesian.get_category(position).map(str::to_string);
+            }
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        container(
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .title("Requests per service")
+                .y_label("requests / s")
+                .legend(LegendPosition::UpperRight)
+                .push_series(
+                    bar_series(SERVICES.into_iter().zip([120, 340, 910, 450, 260])).label("Monday"),
+                )
+                .push_series(
+                    bar_series(SERVICES.into_iter().zip([150, 310, 870, 520, 240]))
+                        .label("Tuesday"),
+                )
+                .on_move(Message::MouseMoved),
+        )
+        .into()
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use pliced::{Chart, line_series, point_series};
+use pliced::{Chart, LegendPosition, line_series, point_series};
 
 use iced::{Element, Length, Task, widget::container};
 
@@ -39,16 +39,24 @@ impl App {
             Chart::new()
                 .width(Length::Fill)
                 .height(Length::Fill)
+                .title("Parabolas")
+                .x_label("x")
+                .y_label("y")
+                .legend(LegendPosition::UpperLeft)
                 .push_series(
-                    line_series(self.data.iter().copied()).color(iced::Color::from_rgb8(255, 0, 0)),
+                    line_series(self.data.iter().copied())
+                        .color(iced::Color::from_rgb8(255, 0, 0))
+                        .label("x^2"),
                 )
                 .push_series(
                     line_series(self.data.iter().copied().map(|(x, y)| (x, y * 0.5)))
-                        .color(iced::Color::from_rgb8(0, 255, 0)),
+                        .color(iced::Color::from_rgb8(0, 255, 0))
+                        .label("x^2 / 2"),
                 )
-                .push_series(point_series(
-                    self.data.iter().copied().map(|(x, y)| (x + 0.5, y * 2.0)),
-                )),
+                .push_series(
+                    point_series(self.data.iter().copied().map(|(x, y)| (x + 0.5, y * 2.0)))
+                        .label("2 (x - 0.5)^2"),
+                ),
         )
         .into()
     }
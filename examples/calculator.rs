@@ -0,0 +1,86 @@
+use pliced::{Chart, Function, LineSeries, expression::Expression};
+
+use iced::{
+    Element, Length, Task,
+    widget::{column, text, text_input},
+};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    InputChanged(String),
+}
+
+struct App {
+    input: String,
+    /// The last expression which parsed, kept while the input has an error.
+    expression: Option<Expression>,
+    error: Option<String>,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        let input = "sin(3x) / x".to_string();
+
+        (
+            Self {
+                expression: Expression::parse(&input).ok(),
+                input,
+                error: None,
+            },
+            Task::none(),
+        )
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {
+            Message::InputChanged(input) => {
+                match Expression::parse(&input) {
+                    Ok(expression) => {
+                        self.expression = Some(expression);
+                        self.error = None;
+                    }
+                    Err(error) => {
+                        // Point at the error below the input
+                        let column = input[..error.position.start].chars().count();
+                        self.error = Some(format!("{}^ {}", " ".repeat(column), error.kind));
+                    }
+                }
+
+                self.input = input;
+            }
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let mut chart = Chart::new()
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .x_range(-10.0..10.0)
+            .y_range(-5.0..5.0);
+
+        if let Some(expression) = &self.expression {
+            chart = chart.push_series(
+                LineSeries::from_source(Function::from(expression.clone())).label(&self.input),
+            );
+        }
+
+        column![
+            text_input("f(x)", &self.input)
+                .font(iced::Font::MONOSPACE)
+                .on_input(Message::InputChanged),
+            text(self.error.as_deref().unwrap_or_default()).font(iced::Font::MONOSPACE),
+            chart,
+        ]
+        .into()
+    }
+}
@@ -0,0 +1,97 @@
+use std::ops::Range;
+
+use pliced::{Cartesian, Chart, function_series, implicit_series, parametric_series};
+
+use iced::{
+    Element, Length, Point, Task,
+    mouse::ScrollDelta,
+    widget::{column, row},
+};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    MouseWheelScrolled(Point, ScrollDelta, Cartesian),
+}
+
+struct App {
+    x_range: Range<f64>,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                x_range: -10.0..10.0,
+            },
+            Task::none(),
+        )
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {
+            Message::MouseWheelScrolled(position, delta, cartesian) => {
+                let ScrollDelta::Lines { y, .. } = delta else {
+                    return Task::none();
+                };
+                let Some(position) = cartesian.get_coords(position) else {
+                    return Task::none();
+                };
+
+                // The functions are sampled again at every zoom level
+                let factor = if y.is_sign_positive() { 0.8 } else { 1.25 };
+                let x = f64::from(position.x);
+
+                self.x_range =
+                    x - (x - self.x_range.start) * factor..x + (self.x_range.end - x) * factor;
+            }
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        column![
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .x_range(self.x_range.clone())
+                .y_range(-5.0..5.0)
+                .push_series(function_series(f64::tan).label("tan(x)"))
+                .push_series(function_series(|x| (1.0 / x).sin()).label("sin(1/x)"))
+                .push_series(function_series(f64::floor).label("floor(x)"))
+                .on_scroll(Message::MouseWheelScrolled),
+            row![
+                Chart::new()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .push_series(
+                        parametric_series(
+                            |t| ((3.0 * t).sin(), (4.0 * t).sin()),
+                            0.0..std::f64::consts::TAU
+                        )
+                        .label("Lissajous")
+                    ),
+                Chart::new()
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .push_series(
+                        implicit_series(
+                            |x, y| (x * x + y * y - 1.0).powi(3) - x * x * y.powi(3),
+                            -1.5..1.5,
+                            -1.5..1.5
+                        )
+                        .label("heart")
+                    ),
+            ],
+        ]
+        .into()
+    }
+}
@@ -0,0 +1,57 @@
+use std::fmt::Debug;
+
+use pliced::{Chart, Colormap, heatmap_series};
+
+use iced::{Element, Length, Task, widget::container};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+#[derive(Debug, Default)]
+struct App {
+    edges: Vec<f32>,
+    values: Vec<f32>,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        let edges: Vec<f32> = (-20..=20).map(|i| i as f32 / 10.0).collect();
+        let centers: Vec<f32> = edges.windows(2).map(|e| (e[0] + e[1]) / 2.0).collect();
+
+        let values = centers
+            .iter()
+            .flat_map(|y| centers.iter().map(move |x| (-(x * x + y * y)).exp()))
+            .collect();
+
+        (Self { edges, values }, Task::none())
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, _msg: Message) -> Task<Message> {
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        container(
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .push_series(
+                    heatmap_series(
+                        self.edges.iter().copied(),
+                        self.edges.iter().copied(),
+                        self.values.iter().copied(),
+                    )
+                    .colormap(Colormap::VIRIDIS),
+                ),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,102 @@
+use pliced::{BoxplotSeries, Cartesian, Chart, LegendPosition, ViolinSeries};
+
+use iced::{
+    Element, Length, Point, Task,
+    widget::{column, text},
+};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    MouseMoved(Point, Cartesian),
+}
+
+struct App {
+    boxes: BoxplotSeries,
+    violins: ViolinSeries,
+    hovered: Option<String>,
+}
+
+const SERVICES: [&str; 5] = ["auth", "billing", "gateway", "search", "storage"];
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        // Skewed latencies in ms with deterministic noise and a few slow requests
+        let latencies = SERVICES.iter().enumerate().map(|(service, name)| {
+            let base = 20.0 + 15.0 * service as f64;
+            let samples = (0..300).map(move |i| {
+                let noise = ((i as f64 * 12.9898 + service as f64 * 78.233).sin() * 43758.545)
+                    .fract()
+                    .abs();
+                let slow = if i % 97 == 0 { 4.0 } else { 1.0 };
+
+                base * (1.0 + noise * noise * 2.0) * slow
+            });
+
+            (*name, samples)
+        });
+        let latencies: Vec<(&str, Vec<f64>)> = latencies
+            .map(|(name, samples)| (name, samples.collect()))
+            .collect();
+
+        (
+            Self {
+                boxes: BoxplotSeries::new(latencies.clone()).label("quartiles"),
+                violins: ViolinSeries::new(latencies).label("density"),
+                hovered: None,
+            },
+            Task::none(),
+        )
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {
+            Message::MouseMoved(position, cartesian) => {
+                self.hovered = cartesian.get_category(position).map(str::to_string);
+            }
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let tooltip = self
+            .hovered
+            .as_deref()
+            .and_then(|service| Some((service, self.boxes.summary(service)?)))
+            .map_or(String::new(), |(service, summary)| {
+                format!(
+                    "{service}: median {:.1} ms, IQR {:.1}-{:.1} ms, whiskers {:.1}-{:.1} ms, \
+                     {} outliers of {} requests",
+                    summary.median,
+                    summary.lower_quartile,
+                    summary.upper_quartile,
+                    summary.lower_whisker,
+                    summary.upper_whisker,
+                    summary.outliers.len(),
+                    summary.count,
+                )
+            });
+
+        column![
+            text(tooltip),
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .title("Latency per service")
+                .y_label("ms")
+                .legend(LegendPosition::UpperRight)
+                .push_series(self.violins.clone())
+                .push_series(self.boxes.clone())
+                .on_move(Message::MouseMoved),
+        ]
+        .into()
+    }
+}
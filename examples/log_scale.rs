@@ -0,0 +1,53 @@
+use std::fmt::Debug;
+
+use pliced::{AxisOptions, Chart, LegendPosition, Scale, line_series};
+
+use iced::{Element, Length, Task, widget::container};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+#[derive(Debug, Default)]
+struct App {
+    data: Vec<(f32, f32)>,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        let data = (1..=100)
+            .map(|x| x as f32 / 10.0)
+            .map(|x| (x, x.exp()))
+            .collect();
+
+        (Self { data }, Task::none())
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, _msg: Message) -> Task<Message> {
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        container(
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .title("Exponential growth")
+                .y_scale(Scale::LOG10)
+                .y_axis(AxisOptions::new().si())
+                .legend(LegendPosition::UpperLeft)
+                .push_series(line_series(self.data.iter().copied()).label("e^x"))
+                .push_series(
+                    line_series(self.data.iter().map(|&(x, _)| (x, x.powi(3)))).label("x^3"),
+                ),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,75 @@
+use pliced::{Chart, Derivation, Regression, point_series};
+
+use iced::{
+    Element, Length, Task,
+    widget::{column, text},
+};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+struct App {
+    data: Vec<(f64, f64)>,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        // A parabola with deterministic noise
+        let data = (0..120)
+            .map(|i| i as f64 / 10.0)
+            .map(|x| {
+                let noise = ((x * 12.9898).sin() * 43758.545).fract();
+
+                (x, 0.3 * x * x - 2.0 * x + 4.0 + noise * 3.0)
+            })
+            .collect();
+
+        (Self { data }, Task::none())
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {}
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let samples = point_series(self.data.iter().copied()).label("samples");
+        let quadratic = samples
+            .derive(Derivation::Regression(Regression::Polynomial(2)))
+            .confidence(0.95)
+            .label("quadratic fit");
+        let coefficients = quadratic.fit.as_ref().map_or(String::new(), |fit| {
+            format!(
+                "y = {:.3}x² + {:.3}x + {:.3}, R² = {:.3}",
+                fit.coefficients[2], fit.coefficients[1], fit.coefficients[0], fit.r_squared
+            )
+        });
+
+        column![
+            text(coefficients),
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .push_series(
+                    samples
+                        .derive(Derivation::MovingAverage(10))
+                        .label("SMA 10")
+                )
+                .push_series(
+                    samples
+                        .derive(Derivation::Regression(Regression::Loess(0.3)))
+                        .label("LOESS")
+                )
+                .push_series(quadratic)
+                .push_series(samples),
+        ]
+        .into()
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt::Debug;
+
+use pliced::{Axis, AxisOptions, Cartesian, Chart, LegendPosition, line_series, point_series};
+
+use iced::{Element, Length, Point, Task, widget::container};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    MouseMoved(Point, Cartesian),
+}
+
+#[derive(Debug, Default)]
+struct App {
+    latency: Vec<(f64, f64)>,
+    throughput: Vec<(f64, f64)>,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        let latency = (0..60)
+            .map(|t| t as f64)
+            .map(|t| (t, 20.0 + 5.0 * (t / 6.0).sin()))
+            .collect();
+        let throughput = (0..60)
+            .map(|t| t as f64)
+            .map(|t| (t, 1200.0 + 400.0 * (t / 9.0).cos()))
+            .collect();
+
+        (
+            Self {
+                latency,
+                throughput,
+            },
+            Task::none(),
+        )
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {
+            Message::MouseMoved(position, cartesian) => {
+                if let Some((latency, throughput)) = cartesian.get_y_values(position) {
+                    println!("latency: {latency:.1} ms, throughput: {throughput:.0?} req/s");
+                }
+            }
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        container(
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .x_label("time (s)")
+                .y_label("latency")
+                .y_axis(AxisOptions::new().unit("ms"))
+                .secondary_y_label("throughput")
+                .secondary_y_axis(AxisOptions::new().si().unit("req/s"))
+                .legend(LegendPosition::UpperLeft)
+                .push_series(line_series(self.latency.iter().copied()).label("latency"))
+                .push_series(
+                    point_series(self.throughput.iter().copied())
+                        .axis(Axis::Secondary)
+                        .label("throughput"),
+                )
+                .on_move(Message::MouseMoved),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,72 @@
+use pliced::{Chart, Data, LineSeries};
+
+use iced::{
+    Element, Length, Task,
+    widget::{button, canvas, column},
+};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    AddPoints,
+}
+
+#[derive(Debug, Default)]
+struct App {
+    data: Data,
+    cache: canvas::Cache,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        let data = Data::new(
+            (0..100_000)
+                .map(|x| x as f64)
+                .map(|x| (x, (x / 500.0).sin())),
+        );
+
+        (
+            Self {
+                data,
+                ..Default::default()
+            },
+            Task::none(),
+        )
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {
+            // The new version of the data clears the cache of the chart
+            Message::AddPoints => self.data.update(|points| {
+                let start = points.len();
+
+                points.extend(
+                    (start..start + 10_000)
+                        .map(|x| x as f64)
+                        .map(|x| (x, (x / 500.0).sin())),
+                );
+            }),
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        column![
+            button("Add points").on_press(Message::AddPoints),
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .with_cache(&self.cache)
+                .push_series(LineSeries::from_source(self.data.clone())),
+        ]
+        .into()
+    }
+}
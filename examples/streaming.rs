@@ -0,0 +1,100 @@
+use std::time::Instant;
+
+use pliced::{Chart, StreamingSeries};
+
+use iced::{
+    Element, Length, Subscription, Task,
+    mouse::ScrollDelta,
+    widget::{button, column, text},
+    window,
+};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view)
+        .subscription(App::subscription)
+        .run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Frame(Instant),
+    MouseWheelScrolled(ScrollDelta),
+    Follow,
+}
+
+struct App {
+    start: Instant,
+    /// The number of samples pushed so far.
+    samples: u64,
+    signal: StreamingSeries,
+}
+
+impl App {
+    /// The number of samples produced per second.
+    const RATE: f64 = 5000.0;
+
+    pub fn new() -> (Self, Task<Message>) {
+        (
+            Self {
+                start: Instant::now(),
+                samples: 0,
+                signal: StreamingSeries::new(600_000, 10.0).label("signal"),
+            },
+            Task::none(),
+        )
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, msg: Message) -> Task<Message> {
+        match msg {
+            Message::Frame(now) => {
+                let elapsed = now.duration_since(self.start).as_secs_f64();
+                let samples = (elapsed * Self::RATE) as u64;
+
+                self.signal.extend(
+                    (self.samples..samples)
+                        .map(|i| i as f64 / Self::RATE)
+                        .map(|t| (t, (t * 3.0).sin() + 0.3 * (t * 41.0).sin())),
+                );
+                self.samples = samples;
+            }
+            Message::MouseWheelScrolled(delta) => {
+                let ScrollDelta::Lines { y, .. } = delta else {
+                    return Task::none();
+                };
+
+                // Scrolling pans back in history, which stops following the newest samples
+                self.signal.pan(f64::from(y));
+            }
+            Message::Follow => self.signal.follow(),
+        }
+
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        let status = if self.signal.is_following() {
+            "following"
+        } else {
+            "paused"
+        };
+
+        column![
+            button(text(status)).on_press(Message::Follow),
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .x_range(self.signal.x_range())
+                .push_series(&self.signal)
+                .on_scroll(|_, delta, _| Message::MouseWheelScrolled(delta)),
+        ]
+        .into()
+    }
+
+    fn subscription(&self) -> Subscription<Message> {
+        window::frames().map(Message::Frame)
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt::Debug;
+use std::time::{Duration, SystemTime};
+
+use pliced::{Chart, Scale, line_series};
+
+use iced::{Element, Length, Task, widget::container};
+
+fn main() -> Result<(), iced::Error> {
+    iced::application(App::title, App::update, App::view).run_with(App::new)
+}
+
+#[derive(Debug, Clone)]
+enum Message {}
+
+#[derive(Debug, Default)]
+struct App {
+    data: Vec<(SystemTime, f32)>,
+}
+
+impl App {
+    pub fn new() -> (Self, Task<Message>) {
+        let now = SystemTime::now();
+        let data = (0..=48 * 60)
+            .map(|minute| {
+                let time = now - Duration::from_secs(minute * 60);
+                let value = (minute as f32 / 120.0).sin() + (minute as f32 / 7.0).cos() * 0.1;
+
+                (time, value)
+            })
+            .collect();
+
+        (Self { data }, Task::none())
+    }
+
+    pub fn title(&self) -> String {
+        "pliced".to_string()
+    }
+
+    pub fn update(&mut self, _msg: Message) -> Task<Message> {
+        Task::none()
+    }
+
+    pub fn view(&self) -> Element<'_, Message> {
+        container(
+            Chart::new()
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .title("Last two days")
+                .x_scale(Scale::Time)
+                .push_series(line_series(self.data.iter().copied())),
+        )
+        .into()
+    }
+}
@@ -1,31 +1,83 @@
 mod cartesian;
 
+pub mod colormap;
+pub mod style;
+
+mod axis;
 mod backend;
+mod bar;
+mod boxplot;
+mod cull;
+mod data;
+mod derived;
+pub mod downsample;
+mod error_bar;
 mod event;
+pub mod expression;
+mod function;
+mod heatmap;
+mod legend;
 mod program;
+mod range;
+mod scale;
+mod source;
+mod stream;
+mod time;
 mod utils;
+mod value;
+mod violin;
 
+pub use axis::{Axis, AxisOptions, Formatter, TickFormat, Ticks};
 pub use backend::IcedChartBackend;
+pub use bar::{BarSeries, bar_series};
+pub use boxplot::{BoxSummary, BoxplotSeries, Whiskers, boxplot_series};
 pub use cartesian::Cartesian;
+pub use colormap::Colormap;
+pub use data::Data;
+pub use derived::{Derivation, DerivedSeries, Fit, Regression};
+pub use downsample::Downsample;
+pub use error_bar::{ErrorBars, ErrorValue};
+pub use expression::Expression;
+pub use function::{
+    Function, Implicit, Parametric, function_series, implicit_series, parametric_series,
+};
+pub use heatmap::{HeatmapSeries, heatmap_series};
+pub use legend::{Legend, LegendEvent, LegendPosition};
 pub use program::Program;
+pub use range::AutoRange;
+pub use scale::{Extent, Scale};
+pub use source::DataSource;
+pub use stream::StreamingSeries;
+pub use style::{Catalog, Status, Style, StyleFn};
+pub use value::AxisValue;
+pub use violin::{ViolinSeries, violin_series};
 
 use iced::advanced::graphics::geometry;
 use iced::advanced::widget::{Tree, tree};
 use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer, text};
 use iced::widget::canvas;
 use iced::widget::text::Shaping;
-use iced::{Element, Length, Rectangle, Size, mouse::Cursor};
+use iced::{Element, Length, Padding, Rectangle, Size, mouse::Cursor};
 use iced::{Point, Renderer, Vector, touch};
 
-use plotters::coord::types::RangedCoordf32;
+use plotters::chart::{DualCoordChartContext, SeriesAnno};
+use plotters::coord::ranged1d::ValueFormatter;
+use plotters::element::{Drawable, PointCollection};
 use plotters::prelude::*;
 use plotters::style::Color as _;
 use plotters_backend::BackendColor;
 use plotters_backend::text_anchor::Pos;
+use scale::ScaledRange;
 
 use core::f32;
+use std::borrow::{Borrow, Cow};
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::Range;
+use std::rc::Rc;
+use std::sync::Arc;
 
 pub type ChartBuilderFn<Renderer = iced::Renderer> =
     Box<dyn for<'a, 'b> Fn(&mut ChartBuilder<'a, 'b, IcedChartBackend<'b, Renderer>>)>;
@@ -60,11 +112,13 @@ pub struct Chart<
     renderer_: PhantomData<Renderer>,
 }
 
-impl<'a, Message, Theme, Renderer> Chart<'_, Message, Attributes<'a, Message>, Theme, Renderer>
+impl<'a, Message, Theme, Renderer>
+    Chart<'_, Message, Attributes<'a, Message, Theme>, Theme, Renderer>
 where
     Message: Clone,
+    Theme: Catalog,
     Renderer: geometry::Renderer,
-    Attributes<'a, Message>: Program<Message, Theme, Renderer>,
+    Attributes<'a, Message, Theme>: Program<Message, Theme, Renderer>,
 {
     pub fn new() -> Self {
         let program = Attributes::default();
@@ -72,66 +126,117 @@ where
         Self::from_program(program)
     }
 
-    pub fn x_range(mut self, range: Range<f32>) -> Self {
-        self.program.x_range = AxisRange::Custom(range);
+    pub fn x_range(mut self, range: Range<impl AxisValue>) -> Self {
+        self.program.x_range = AxisRange::Custom(range.start.to_f64()..range.end.to_f64());
 
         self
     }
 
-    pub fn y_range(mut self, range: Range<f32>) -> Self {
-        self.program.y_range = AxisRange::Custom(range);
+    pub fn y_range(mut self, range: Range<impl AxisValue>) -> Self {
+        self.program.y_range = AxisRange::Custom(range.start.to_f64()..range.end.to_f64());
 
         self
     }
 
-    pub fn push_series(mut self, series: impl Into<Series>) -> Self {
-        let series = series.into();
+    /// Sets the range of the secondary y axis, drawn on the right.
+    pub fn secondary_y_range(mut self, range: Range<impl AxisValue>) -> Self {
+        self.program.secondary_y_range =
+            AxisRange::Custom(range.start.to_f64()..range.end.to_f64());
 
-        if let AxisRange::Automatic(x_range) = self.program.x_range {
-            let x_min_cur = x_range.as_ref().map_or(f32::INFINITY, |range| range.start);
-            let x_max_cur = x_range
-                .as_ref()
-                .map_or(f32::NEG_INFINITY, |range| range.end);
+        self
+    }
 
-            let (x_min, x_max) = {
-                let iter = match &series {
-                    Series::Line(line_series) => line_series.data.iter(),
-                    Series::Point(point_series) => point_series.data.iter(),
-                };
+    /// Sets the [`Scale`] of the x axis.
+    ///
+    /// Panics if the base of a logarithmic scale is invalid, see [`Scale::log`].
+    pub fn x_scale(mut self, scale: Scale) -> Self {
+        self.program.x_scale = scale.validated();
+        self
+    }
 
-                iter.fold((x_min_cur, x_max_cur), |(x_min, x_max), (cur_x, _)| {
-                    (x_min.min(*cur_x), x_max.max(*cur_x))
-                })
-            };
+    /// Sets the [`Scale`] of the y axis.
+    ///
+    /// Panics if the base of a logarithmic scale is invalid, see [`Scale::log`].
+    pub fn y_scale(mut self, scale: Scale) -> Self {
+        self.program.y_scale = scale.validated();
+        self
+    }
+
+    /// Sets the order of the categories on the x axis.
+    ///
+    /// Categories of [`BarSeries`], [`BoxplotSeries`] and [`ViolinSeries`] missing from the list
+    /// are appended in order of appearance.
+    pub fn x_categories(mut self, categories: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let mut categories: Vec<String> = categories.into_iter().map(Into::into).collect();
 
-            self.program.x_range = AxisRange::Automatic(Some(x_min..x_max));
+        for category in self.program.x_categories.drain(..) {
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
         }
 
-        if let AxisRange::Automatic(y_range) = self.program.y_range {
-            let y_min_cur = y_range.as_ref().map_or(f32::INFINITY, |range| range.start);
-            let y_max_cur = y_range
-                .as_ref()
-                .map_or(f32::NEG_INFINITY, |range| range.end);
+        self.program.x_categories = categories;
+        self
+    }
 
-            let (y_min, y_max) = {
-                let iter = match &series {
-                    Series::Line(line_series) => line_series.data.iter(),
-                    Series::Point(point_series) => point_series.data.iter(),
-                };
+    /// Sets the [`Scale`] of the secondary y axis.
+    ///
+    /// Panics if the base of a logarithmic scale is invalid, see [`Scale::log`].
+    pub fn secondary_y_scale(mut self, scale: Scale) -> Self {
+        self.program.secondary_y_scale = scale.validated();
+        self
+    }
 
-                iter.fold((y_min_cur, y_max_cur), |(y_min, y_max), (_, cur_y)| {
-                    (y_min.min(*cur_y), y_max.max(*cur_y))
-                })
-            };
+    /// Sets the ticks, labels and line of the x axis.
+    pub fn x_axis(mut self, options: AxisOptions) -> Self {
+        self.program.x_axis = options;
+        self
+    }
+
+    /// Sets the ticks, labels and line of the y axis.
+    pub fn y_axis(mut self, options: AxisOptions) -> Self {
+        self.program.y_axis = options;
+        self
+    }
+
+    /// Sets the ticks, labels and line of the secondary y axis.
+    pub fn secondary_y_axis(mut self, options: AxisOptions) -> Self {
+        self.program.secondary_y_axis = options;
+        self
+    }
+
+    pub fn push_series(mut self, series: impl Into<Series>) -> Self {
+        let series = series.into();
+
+        let categories: Vec<&str> = match &series {
+            Series::Bar(bar_series) => bar_series.categories().collect(),
+            Series::Boxplot(boxplot_series) => boxplot_series.categories().collect(),
+            Series::Violin(violin_series) => violin_series.categories().collect(),
+            _ => Vec::new(),
+        };
 
-            self.program.y_range = AxisRange::Automatic(Some(y_min..y_max));
+        for category in categories {
+            if !self.program.x_categories.iter().any(|c| c == category) {
+                self.program.x_categories.push(category.to_string());
+            }
         }
 
+        self.program
+            .bounds
+            .push((series.x_bounds(), series.y_bounds()));
         self.program.series.push(series);
 
         self
     }
 
+    /// Sets the colors assigned in order to series without an explicit color.
+    ///
+    /// By default, the colors are derived from the palette of the theme.
+    pub fn palette(mut self, colors: impl IntoIterator<Item = impl Into<Color>>) -> Self {
+        self.program.palette = Some(colors.into_iter().map(Into::into).collect());
+        self
+    }
+
     pub fn extend_series(
         self,
         series_list: impl IntoIterator<Item = impl Into<Series>> + Clone,
@@ -139,6 +244,83 @@ where
         series_list.into_iter().fold(self, Self::push_series)
     }
 
+    /// Sets the caption drawn above the plotting area.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.program.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description drawn below the x axis.
+    pub fn x_label(mut self, label: impl Into<String>) -> Self {
+        self.program.x_label = Some(label.into());
+        self
+    }
+
+    /// Sets the description drawn next to the y axis.
+    pub fn y_label(mut self, label: impl Into<String>) -> Self {
+        self.program.y_label = Some(label.into());
+        self
+    }
+
+    /// Sets the description drawn next to the secondary y axis.
+    pub fn secondary_y_label(mut self, label: impl Into<String>) -> Self {
+        self.program.secondary_y_label = Some(label.into());
+        self
+    }
+
+    /// Sets the space around the chart, which is 20 pixels on every side by default.
+    pub fn margin(mut self, margin: impl Into<Padding>) -> Self {
+        self.program.margin = margin.into();
+        self
+    }
+
+    /// Sets the height of the area below the plotting area holding the x tick labels.
+    ///
+    /// By default, the height is derived from the font size of the labels.
+    pub fn x_label_area_size(mut self, size: u32) -> Self {
+        self.program.x_label_area_size = Some(size);
+        self
+    }
+
+    /// Sets the width of the area left of the plotting area holding the y tick labels.
+    ///
+    /// By default, the width is derived from the widest label.
+    pub fn y_label_area_size(mut self, size: u32) -> Self {
+        self.program.y_label_area_size = Some(size);
+        self
+    }
+
+    /// Shows a legend listing every series with a label.
+    pub fn legend(mut self, legend: impl Into<Legend>) -> Self {
+        self.program.legend = Some(legend.into());
+        self
+    }
+
+    /// Sets the style of the [`Chart`].
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.program.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Chart`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.program.class = class.into();
+        self
+    }
+
+    /// Sets the message produced when an entry of the legend is clicked.
+    ///
+    /// The [`Chart`] then no longer hides series by itself, use [`LineSeries::visible`] and
+    /// [`PointSeries::visible`] instead. The cache of the [`Chart`] has to be cleared after
+    /// changing the visibility.
+    pub fn on_series_toggle(mut self, msg: impl Fn(LegendEvent) -> Message + 'a) -> Self {
+        self.program.on_series_toggle = Some(Box::new(msg));
+        self
+    }
+
     pub fn on_press(mut self, msg: Message) -> Self {
         self.on_press = Some(msg);
         self
@@ -200,6 +382,8 @@ where
         self
     }
 
+    /// Draws the chart into `cache`, which is cleared when the version of a [`DataSource`]
+    /// changes.
     pub fn with_cache(mut self, cache: &'a geometry::Cache<Renderer>) -> Self {
         self.cache = Some(cache);
         self
@@ -265,6 +449,14 @@ where
 
         let default_font_size = renderer.default_size();
         let geometry = if let Some(cache) = &self.cache {
+            let version = self.program.data_version();
+
+            let last_version = &tree.state.downcast_ref::<State>().data_version;
+
+            if last_version.replace(version) != version {
+                cache.clear();
+            }
+
             cache.draw(renderer, bounds.size(), |frame| {
                 let root = IcedChartBackend::new(frame, default_font_size, self.shaping)
                     .into_drawing_area();
@@ -311,15 +503,13 @@ where
         let bounds = layout.bounds();
 
         if state.cursor_position != cursor_position || state.bounds != bounds {
-            if let Some(message) = self.on_press.as_ref() {
-                if let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
-                | iced::Event::Touch(touch::Event::FingerPressed { .. }) = event
-                {
-                    shell.publish(message.clone());
-
-                    return;
-                }
-            }
+            let on_press = self.on_press.as_ref().filter(|_| {
+                matches!(
+                    event,
+                    iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                        | iced::Event::Touch(touch::Event::FingerPressed { .. })
+                )
+            });
 
             let canvas_event = match event {
                 iced::Event::Mouse(mouse_event) => Some(event::Event::Mouse(*mouse_event)),
@@ -334,13 +524,34 @@ where
             if let Some(canvas_event) = canvas_event {
                 let state = tree.children[0].state.downcast_mut::<P::State>();
 
-                let (_event_status, message) =
+                let (event_status, message) =
                     self.program.update(state, canvas_event, bounds, cursor);
 
-                if let Some(message) = message {
+                // A press captured by the program, like a click on the legend, is not a press
+                // on the chart
+                if event_status == event::Status::Captured {
+                    match message {
+                        Some(message) => shell.publish(message),
+                        None => {
+                            // the program changed its own state, so the cached geometry is stale
+                            if let Some(cache) = self.cache {
+                                cache.clear();
+                            }
+                            shell.request_redraw();
+                        }
+                    }
+
+                    return;
+                }
+
+                if let (None, Some(message)) = (on_press, message) {
                     shell.publish(message);
                 }
             }
+
+            if let Some(message) = on_press {
+                shell.publish(message.clone());
+            }
         }
     }
 
@@ -365,14 +576,16 @@ struct State {
     //is_hovered: bool,
     bounds: Rectangle,
     cursor_position: Option<Point>,
+    data_version: Cell<u64>,
 }
 
 impl<'a, Message, Theme, Renderer> Default
-    for Chart<'a, Message, Attributes<'a, Message>, Theme, Renderer>
+    for Chart<'a, Message, Attributes<'a, Message, Theme>, Theme, Renderer>
 where
     Message: Clone,
+    Theme: Catalog,
     Renderer: 'a + geometry::Renderer,
-    Attributes<'a, Message>: Program<Message, Theme, Renderer>,
+    Attributes<'a, Message, Theme>: Program<Message, Theme, Renderer>,
 {
     fn default() -> Self {
         Self::new()
@@ -397,34 +610,85 @@ where
 type OnScrollFn<'a, Message> =
     Box<dyn Fn(iced::Point, mouse::ScrollDelta, Cartesian) -> Message + 'a>;
 
-pub struct Attributes<'a, Message>
+pub struct Attributes<'a, Message, Theme = iced::Theme>
 where
     Message: Clone,
+    Theme: Catalog,
 {
-    x_range: AxisRange<Range<f32>>,
-    y_range: AxisRange<Range<f32>>,
+    x_range: AxisRange<Range<f64>>,
+    y_range: AxisRange<Range<f64>>,
+    x_scale: Scale,
+    y_scale: Scale,
+    x_categories: Vec<String>,
+    x_axis: AxisOptions,
+    y_axis: AxisOptions,
+    secondary_y_range: AxisRange<Range<f64>>,
+    secondary_y_scale: Scale,
+    secondary_y_axis: AxisOptions,
+    secondary_y_label: Option<String>,
     series: Vec<Series>,
+    bounds: Vec<(Extent, Extent)>,
+    title: Option<String>,
+    x_label: Option<String>,
+    y_label: Option<String>,
+    margin: Padding,
+    x_label_area_size: Option<u32>,
+    y_label_area_size: Option<u32>,
+    palette: Option<Vec<Color>>,
+    legend: Option<Legend>,
+    class: Theme::Class<'a>,
 
     on_move: Option<Box<dyn Fn(iced::Point, Cartesian) -> Message + 'a>>,
     on_scroll: Option<OnScrollFn<'a, Message>>,
+    on_series_toggle: Option<Box<dyn Fn(LegendEvent) -> Message + 'a>>,
 }
 
-impl<Message> Default for Attributes<'_, Message>
+impl<Message, Theme> Default for Attributes<'_, Message, Theme>
 where
     Message: Clone,
+    Theme: Catalog,
 {
     fn default() -> Self {
         Self {
             x_range: Default::default(),
             y_range: Default::default(),
+            x_scale: Default::default(),
+            y_scale: Default::default(),
+            x_categories: Default::default(),
+            x_axis: Default::default(),
+            y_axis: Default::default(),
+            secondary_y_range: Default::default(),
+            secondary_y_scale: Default::default(),
+            secondary_y_axis: Default::default(),
+            secondary_y_label: Default::default(),
             series: Default::default(),
+            bounds: Default::default(),
+            title: Default::default(),
+            x_label: Default::default(),
+            y_label: Default::default(),
+            margin: Padding::new(Self::MARGIN),
+            x_label_area_size: Default::default(),
+            y_label_area_size: Default::default(),
+            palette: Default::default(),
+            legend: Default::default(),
+            class: Theme::default(),
 
             on_move: Default::default(),
             on_scroll: Default::default(),
+            on_series_toggle: Default::default(),
         }
     }
 }
 
+/// Local state of the [`Attributes`] program.
+#[derive(Default)]
+pub struct AttributesState {
+    hidden: HashSet<usize>,
+    legend_entries: legend::Entries,
+    last_click: Option<mouse::Click>,
+    coord_spec: RefCell<Option<Cartesian>>,
+}
+
 #[derive(Clone)]
 pub enum AxisRange<T> {
     Custom(T),
@@ -437,108 +701,999 @@ impl<T> Default for AxisRange<T> {
     }
 }
 
-impl<Message> Attributes<'_, Message>
+impl<Message, Theme> Attributes<'_, Message, Theme>
 where
     Message: Clone,
+    Theme: Catalog,
+{
+    const X_RANGE_DEFAULT: Range<f64> = 0.0..10.0;
+    const Y_RANGE_DEFAULT: Range<f64> = 0.0..10.0;
+    const MARGIN: f32 = 20.0;
+    const TITLE_SIZE: u32 = 20;
+    const DESCRIPTION_SIZE: u32 = 14;
+    /// The space plotters leaves between an axis and its tick labels.
+    const LABEL_DISTANCE: u32 = 10;
+    const LABEL_AREA_PADDING: u32 = 5;
+
+    fn is_visible(&self, index: usize, state: &AttributesState) -> bool {
+        let visible = match &self.series[index] {
+            Series::Line(line_series) => line_series.visible,
+            Series::Point(point_series) => point_series.visible,
+            Series::Bar(bar_series) => bar_series.visible,
+            Series::Boxplot(boxplot_series) => boxplot_series.visible,
+            Series::Violin(violin_series) => violin_series.visible,
+            Series::Derived(derived_series) => derived_series.visible,
+            Series::Heatmap(_) => true,
+        };
+
+        visible && (self.on_series_toggle.is_some() || !state.hidden.contains(&index))
+    }
+
+    /// Returns whether the secondary y axis is drawn.
+    fn has_secondary_axis(&self) -> bool {
+        matches!(self.secondary_y_range, AxisRange::Custom(_))
+            || self.series.iter().any(|s| s.axis() == Axis::Secondary)
+    }
+
+    /// Returns the x, y and secondary y range, fitting automatic ranges to the visible series.
+    fn ranges(&self, state: &AttributesState) -> (Range<f64>, Range<f64>, Range<f64>) {
+        let visible = || {
+            self.bounds
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| self.is_visible(*index, state))
+        };
+        let x_bounds = visible().fold(Extent::EMPTY, |x, (_, (x_bounds, _))| x.union(x_bounds));
+
+        // Every category keeps its slot, even without visible bars
+        let x_bounds = if self.x_categories.is_empty() {
+            x_bounds
+                .range(&self.x_scale)
+                .map(|range| self.x_axis.auto_range.apply(range, self.x_scale))
+        } else {
+            x_bounds
+                .include(0.0..self.x_categories.len() as f64)
+                .range(&self.x_scale)
+        };
+        let x_range = axis_range(
+            &self.x_range,
+            x_bounds,
+            self.x_scale.default_range(Self::X_RANGE_DEFAULT),
+        );
+
+        let (y_bounds, secondary_y_bounds) = visible().fold(
+            (Extent::EMPTY, Extent::EMPTY),
+            |(y, secondary_y), (index, (_, y_bounds))| {
+                let series = &self.series[index];
+                let y_bounds = series
+                    .y_bounds_within(x_range.clone())
+                    .unwrap_or_else(|| y_bounds.clone());
+
+                match series.axis() {
+                    Axis::Primary => (y.union(&y_bounds), secondary_y),
+                    Axis::Secondary => (y, secondary_y.union(&y_bounds)),
+                }
+            },
+        );
+        let fit = |bounds: Extent, axis| {
+            let scale = self.y_scale(axis);
+
+            bounds
+                .range(&scale)
+                .map(|range| self.y_axis(axis).auto_range.apply(range, scale))
+        };
+
+        (
+            x_range,
+            axis_range(
+                &self.y_range,
+                fit(y_bounds, Axis::Primary),
+                self.y_scale.default_range(Self::Y_RANGE_DEFAULT),
+            ),
+            axis_range(
+                &self.secondary_y_range,
+                fit(secondary_y_bounds, Axis::Secondary),
+                self.secondary_y_scale.default_range(Self::Y_RANGE_DEFAULT),
+            ),
+        )
+    }
+
+    /// Returns the coordinate of the x axis, which is categorical as soon as there are categories.
+    fn x_coord(&self, x_range: Range<f64>) -> ScaledRange {
+        let coord = if self.x_categories.is_empty() {
+            ScaledRange::new(x_range, self.x_scale)
+        } else {
+            ScaledRange::categorical(x_range, Arc::from(self.x_categories.as_slice()))
+        };
+
+        coord.with_ticks(self.x_axis.ticks.clone())
+    }
+
+    fn y_coord(&self, y_range: Range<f64>, axis: Axis) -> ScaledRange {
+        ScaledRange::new(y_range, self.y_scale(axis)).with_ticks(self.y_axis(axis).ticks.clone())
+    }
+
+    fn y_scale(&self, axis: Axis) -> Scale {
+        match axis {
+            Axis::Primary => self.y_scale,
+            Axis::Secondary => self.secondary_y_scale,
+        }
+    }
+
+    fn y_axis(&self, axis: Axis) -> &AxisOptions {
+        match axis {
+            Axis::Primary => &self.y_axis,
+            Axis::Secondary => &self.secondary_y_axis,
+        }
+    }
+
+    /// Returns whether a point can be drawn with the scales of the x and the given y axis.
+    fn contains(&self, (x, y): (f64, f64), axis: Axis) -> bool {
+        self.x_scale.contains(x) && self.y_scale(axis).contains(y)
+    }
+
+    fn on_legend_click(&self, state: &mut AttributesState, event: LegendEvent) {
+        match event {
+            LegendEvent::Toggled(index) => {
+                if !state.hidden.remove(&index) {
+                    state.hidden.insert(index);
+                }
+            }
+            LegendEvent::Isolated(index) => {
+                let others: HashSet<usize> = self
+                    .series
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, s)| *other != index && s.label().is_some())
+                    .map(|(other, _)| other)
+                    .collect();
+
+                state.hidden = if others.is_subset(&state.hidden) {
+                    HashSet::new()
+                } else {
+                    others
+                };
+            }
+        }
+    }
+
+    /// Returns the color of every series, assigning palette colors in order to the series
+    /// without an explicit color.
+    fn series_colors(&self, style: &Style) -> Vec<Option<Color>> {
+        let palette = match &self.palette {
+            Some(palette) if !palette.is_empty() => palette.clone(),
+            _ => style.palette.iter().copied().map(Color).collect(),
+        };
+        let mut automatic = palette.into_iter().cycle();
+
+        self.series
+            .iter()
+            .map(|s| match s {
+                Series::Line(line_series) => line_series.color.or_else(|| automatic.next()),
+                Series::Point(point_series) => point_series.color.or_else(|| automatic.next()),
+                Series::Bar(bar_series) => bar_series.color.or_else(|| automatic.next()),
+                Series::Boxplot(boxplot_series) => {
+                    boxplot_series.color.or_else(|| automatic.next())
+                }
+                Series::Violin(violin_series) => violin_series.color.or_else(|| automatic.next()),
+                Series::Derived(derived_series) => {
+                    derived_series.color.or_else(|| automatic.next())
+                }
+                Series::Heatmap(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns the height of the x label area fitting the tick labels and the description.
+    fn x_label_area(&self, label_style: &TextStyle, description_style: &TextStyle) -> u32 {
+        if let Some(size) = self.x_label_area_size {
+            return size;
+        }
+
+        let labels = if self.x_axis.has_labels() {
+            Self::LABEL_DISTANCE + text_size("0", label_style).height.ceil() as u32
+        } else {
+            0
+        };
+        let description = self.x_label.as_deref().map_or(0.0, |x_label| {
+            text_size(x_label, description_style).height + Self::LABEL_AREA_PADDING as f32
+        });
+
+        labels + description.ceil() as u32
+    }
+
+    /// Returns the width of the y label area fitting the widest tick label and the description.
+    fn y_label_area(
+        &self,
+        y_coord: &ScaledRange,
+        axis: Axis,
+        label_style: &TextStyle,
+        description_style: &TextStyle,
+    ) -> u32 {
+        let (options, y_label) = match axis {
+            Axis::Primary => (&self.y_axis, &self.y_label),
+            Axis::Secondary => (&self.secondary_y_axis, &self.secondary_y_label),
+        };
+
+        if let (Axis::Primary, Some(size)) = (axis, self.y_label_area_size) {
+            return size;
+        }
+
+        let labels = if options.has_labels() {
+            let width = y_coord
+                .labels(options)
+                .into_iter()
+                .map(|(_, label)| text_size(&label, label_style).width)
+                .fold(0.0, f32::max);
+
+            Self::LABEL_DISTANCE + Self::LABEL_AREA_PADDING + width.ceil() as u32
+        } else {
+            0
+        };
+        let description = y_label.as_deref().map_or(0.0, |y_label| {
+            text_size(y_label, description_style).height + Self::LABEL_AREA_PADDING as f32
+        });
+
+        labels + description.ceil() as u32
+    }
+}
+
+fn text_size(text: &str, style: &TextStyle) -> Size {
+    backend::measure_text(
+        text,
+        style,
+        (style.font.get_size() as f32).into(),
+        Shaping::default(),
+        Size::new(f32::INFINITY, f32::INFINITY),
+    )
+}
+
+/// Returns where bars on an axis with `scale` and `range` start.
+fn bar_baseline(scale: Scale, range: &Range<f64>) -> f64 {
+    if scale.contains(0.0) {
+        0.0
+    } else {
+        range.start.min(range.end)
+    }
+}
+
+type Coord = Cartesian2d<ScaledRange, ScaledRange>;
+
+/// Draws a series against the given y axis.
+fn draw_series<'a, 'c, DB, E, R, S>(
+    chart: &'c mut DualCoordChartContext<'a, DB, Coord, Coord>,
+    axis: Axis,
+    series: S,
+) -> &'c mut SeriesAnno<'a, DB>
+where
+    DB: DrawingBackend,
+    for<'b> &'b E: PointCollection<'b, (f64, f64)>,
+    E: Drawable<DB>,
+    R: Borrow<E>,
+    S: IntoIterator<Item = R>,
 {
-    const X_RANGE_DEFAULT: Range<f32> = 0.0..10.0;
-    const Y_RANGE_DEFAULT: Range<f32> = 0.0..10.0;
+    match axis {
+        Axis::Primary => chart.draw_series(series).unwrap(),
+        Axis::Secondary => chart.draw_secondary_series(series).unwrap(),
+    }
+}
+
+fn axis_range(
+    range: &AxisRange<Range<f64>>,
+    bounds: Option<Range<f64>>,
+    default: Range<f64>,
+) -> Range<f64> {
+    match (range, bounds) {
+        (AxisRange::Custom(range), _) => range.clone(),
+        (AxisRange::Automatic(Some(initial)), Some(bounds)) => {
+            initial.start.min(bounds.start)..initial.end.max(bounds.end)
+        }
+        (AxisRange::Automatic(Some(initial)), None) => initial.clone(),
+        (AxisRange::Automatic(None), Some(bounds)) => bounds,
+        (AxisRange::Automatic(None), None) => default,
+    }
 }
 
-impl<Message> Program<Message> for Attributes<'_, Message>
+impl<Message, Theme> Program<Message, Theme> for Attributes<'_, Message, Theme>
 where
     Message: Clone,
+    Theme: Catalog,
 {
-    type State = ();
+    type State = AttributesState;
 
     fn draw(
         &self,
-        _state: &Self::State,
+        state: &Self::State,
         chart: &mut ChartBuilder<backend::IcedChartBackend<Renderer>>,
-        theme: &iced::Theme,
-        _bounds: iced::Rectangle,
-        _cursor: mouse::Cursor,
+        theme: &Theme,
+        bounds: iced::Rectangle,
+        cursor: mouse::Cursor,
     ) {
-        let x_range = match self.x_range.clone() {
-            AxisRange::Custom(x_range) => x_range,
-            AxisRange::Automatic(Some(x_range)) => x_range,
-            AxisRange::Automatic(None) => Attributes::<Message>::X_RANGE_DEFAULT,
-        };
-
-        let y_range = match self.y_range.clone() {
-            AxisRange::Custom(y_range) => y_range,
-            AxisRange::Automatic(Some(y_range)) => y_range,
-            AxisRange::Automatic(None) => Attributes::<Message>::Y_RANGE_DEFAULT,
+        let status = if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
         };
+        let style = theme.style(&self.class, status);
+        let (x_range, y_range, secondary_y_range) = self.ranges(state);
+        let baselines = (
+            bar_baseline(self.y_scale, &y_range),
+            bar_baseline(self.secondary_y_scale, &secondary_y_range),
+        );
+        let x_coord = self.x_coord(x_range);
+        let y_coord = self.y_coord(y_range, Axis::Primary);
+        let secondary_y_coord = self.y_coord(secondary_y_range, Axis::Secondary);
+        let secondary = self.has_secondary_axis();
+
+        let colorbar = self.series.iter().find_map(|s| match s {
+            Series::Heatmap(heatmap_series) if heatmap_series.colorbar => Some(heatmap_series),
+            _ => None,
+        });
 
-        let mut chart = chart
-            .x_label_area_size(10)
-            .margin(20)
-            .build_cartesian_2d(x_range, y_range)
-            .unwrap();
-
-        let text_color = Color(theme.palette().text);
+        let text_color = Color(style.label);
         let label_style = TextStyle {
             font: "sans".into(),
             color: text_color.into(),
             pos: Pos::default(),
         };
+        let caption_color = RGBAColor::from(Color(style.caption));
+        let description_style = ("sans-serif", Self::DESCRIPTION_SIZE)
+            .into_font()
+            .color(&caption_color);
+
         chart
-            .configure_mesh()
+            .margin_top(self.margin.top)
+            .margin_left(self.margin.left)
+            .margin_right(self.margin.right)
+            .margin_bottom(self.margin.bottom)
+            .x_label_area_size(self.x_label_area(&label_style, &description_style))
+            .y_label_area_size(self.y_label_area(
+                &y_coord,
+                Axis::Primary,
+                &label_style,
+                &description_style,
+            ));
+
+        let secondary_label_area = if secondary {
+            self.y_label_area(
+                &secondary_y_coord,
+                Axis::Secondary,
+                &label_style,
+                &description_style,
+            )
+        } else {
+            0
+        };
+        let colorbar_area = match colorbar {
+            Some(_) => HeatmapSeries::COLORBAR_AREA_SIZE,
+            None => 0,
+        };
+
+        if secondary_label_area + colorbar_area > 0 {
+            chart.right_y_label_area_size(secondary_label_area + colorbar_area);
+        }
+
+        if let Some(title) = &self.title {
+            chart.caption(
+                title,
+                ("sans-serif", Self::TITLE_SIZE)
+                    .into_font()
+                    .color(&caption_color),
+            );
+        }
+
+        let legend = self
+            .legend
+            .filter(|_| self.series.iter().any(|s| s.label().is_some()))
+            .map(|legend| {
+                let label_style = ("sans-serif", Legend::FONT_SIZE).into_font().into();
+                let size = legend.size(self.series.iter().filter_map(Series::label), |label| {
+                    text_size(label, &label_style).width
+                });
+                let (right, bottom) = legend.reserved(size);
+
+                chart
+                    .margin_right(self.margin.right + right as f32)
+                    .margin_bottom(self.margin.bottom + bottom as f32);
+
+                (legend, size)
+            });
+
+        let mut chart = chart
+            .build_cartesian_2d(x_coord.clone(), y_coord.clone())
+            .unwrap()
+            .set_secondary_coord(x_coord.clone(), secondary_y_coord.clone());
+        *state.coord_spec.borrow_mut() = Some(Cartesian::scaled(
+            chart.as_coord_spec().clone(),
+            secondary.then(|| chart.secondary_plotting_area().as_coord_spec().clone()),
+        ));
+
+        if let Some(background) = style.background {
+            let background: RGBAColor = Color(background).into();
+
+            chart
+                .plotting_area()
+                .use_screen_coord()
+                .draw(&plotters::element::Rectangle::new(
+                    [(0, 0), (bounds.width as i32, bounds.height as i32)],
+                    background.filled(),
+                ))
+                .unwrap();
+        }
+
+        let x_format = |value: &f64| {
+            if self.x_axis.labels {
+                self.x_axis
+                    .label(*value, |value| x_coord.format_ext(&value))
+            } else {
+                String::new()
+            }
+        };
+        let y_format = |value: &f64| {
+            if self.y_axis.labels {
+                self.y_axis
+                    .label(*value, |value| y_coord.format_ext(&value))
+            } else {
+                String::new()
+            }
+        };
+
+        let mut mesh = chart.configure_mesh();
+
+        mesh
             //.disable_mesh()
-            .label_style(label_style)
-            .bold_line_style(GREEN.mix(0.1))
-            .light_line_style(BLUE.mix(0.1))
-            .draw()
-            .unwrap();
+            .label_style(label_style.clone())
+            .axis_desc_style(description_style.clone())
+            .axis_style(ShapeStyle::from(Color(style.axis)).stroke_width(1))
+            .bold_line_style(ShapeStyle::from(Color(style.major_grid)).stroke_width(1))
+            .light_line_style(ShapeStyle::from(Color(style.minor_grid)).stroke_width(1))
+            .x_labels(self.x_axis.tick_count_hint(scale::LABELS))
+            .y_labels(self.y_axis.tick_count_hint(scale::LABELS))
+            .x_label_formatter(&x_format)
+            .y_label_formatter(&y_format);
+
+        if let Some(divisions) = self.x_axis.minor_ticks {
+            mesh.x_max_light_lines(divisions);
+        }
+
+        if let Some(divisions) = self.y_axis.minor_ticks {
+            mesh.y_max_light_lines(divisions);
+        }
+
+        if !self.x_axis.visible {
+            mesh.disable_x_axis();
+        }
+
+        if !self.y_axis.visible {
+            mesh.disable_y_axis();
+        }
+
+        if let Some(x_label) = &self.x_label {
+            mesh.x_desc(x_label);
+        }
+
+        if let Some(y_label) = &self.y_label {
+            mesh.y_desc(y_label);
+        }
+
+        mesh.draw().unwrap();
+
+        if secondary && self.secondary_y_axis.visible {
+            let secondary_y_format = |value: &f64| {
+                if self.secondary_y_axis.labels {
+                    self.secondary_y_axis
+                        .label(*value, |value| secondary_y_coord.format_ext(&value))
+                } else {
+                    String::new()
+                }
+            };
+
+            let mut axes = chart.configure_secondary_axes();
+
+            axes.label_style(label_style.clone())
+                .axis_desc_style(description_style)
+                .axis_style(ShapeStyle::from(Color(style.axis)).stroke_width(1))
+                .y_labels(self.secondary_y_axis.tick_count_hint(scale::LABELS))
+                .y_label_formatter(&secondary_y_format);
+
+            if let Some(y_label) = &self.secondary_y_label {
+                axes.y_desc(y_label);
+            }
+
+            axes.draw().unwrap();
+        }
+
+        if let Some(heatmap_series) = colorbar {
+            // The colorbar goes next to the labels of the secondary y axis
+            let (x_pixels, y_pixels) = chart.plotting_area().get_pixel_range();
+            let x_pixels = x_pixels.start..x_pixels.end + secondary_label_area as i32;
+
+            heatmap_series.draw_colorbar(
+                &chart.plotting_area().use_screen_coord(),
+                (x_pixels, y_pixels),
+                &label_style,
+                text_color,
+            );
+        }
+
+        let series_colors = self.series_colors(&style);
+        let origin = chart.plotting_area().get_base_pixel();
+        let x_visible = x_coord.range();
+        let columns = chart.plotting_area().get_pixel_range().0.len();
+        let slots: HashMap<&str, usize> = self
+            .x_categories
+            .iter()
+            .enumerate()
+            .map(|(slot, category)| (category.as_str(), slot))
+            .collect();
+        let bar_groups: Vec<usize> = self
+            .series
+            .iter()
+            .enumerate()
+            .filter(|(index, s)| matches!(s, Series::Bar(_)) && self.is_visible(*index, state))
+            .map(|(index, _)| index)
+            .collect();
+        let distribution_groups: Vec<usize> = self
+            .series
+            .iter()
+            .enumerate()
+            .filter(|(index, s)| {
+                matches!(s, Series::Boxplot(_) | Series::Violin(_))
+                    && self.is_visible(*index, state)
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        state.legend_entries.clear();
+
+        for (index, (s, color)) in self.series.iter().zip(series_colors).enumerate() {
+            let color = color.unwrap_or(text_color);
+            let visible = self.is_visible(index, state);
+            let glyph_color = if visible { 1.0 } else { 0.25 };
+            let axis = s.axis();
 
-        for s in &self.series {
             match s {
                 Series::Line(line_series) => {
-                    chart
-                        .draw_series(plotters::series::LineSeries::from(line_series))
-                        .unwrap();
+                    let style = ShapeStyle::from(color);
+                    let points = if visible {
+                        line_series.source.downsampled(
+                            x_visible.clone(),
+                            columns,
+                            line_series.downsample,
+                        )
+                    } else {
+                        Cow::Borrowed(&[][..])
+                    };
+                    let annotation = draw_series(
+                        &mut chart,
+                        axis,
+                        line_series
+                            .drawn_segments(&points, |point| self.contains(point, axis))
+                            .map(|segment| PathElement::new(segment.to_vec(), style)),
+                    );
+
+                    if let (Some(label), Some((legend, size))) = (&line_series.label, legend) {
+                        let width = legend.glyph_width as i32;
+                        let glyph_style = ShapeStyle {
+                            color: style.color.mix(glyph_color),
+                            ..style
+                        };
+                        let entries = state.legend_entries.clone();
+
+                        annotation.label(label).legend(move |(x, y)| {
+                            entries.push(index, legend.entry_bounds((x, y), origin, size.0));
+
+                            PathElement::new(vec![(x, y), (x + width, y)], glyph_style)
+                        });
+                    }
                 }
                 Series::Point(point_series) => {
-                    chart
-                        .draw_series(plotters::series::PointSeries::of_element(
-                            point_series.data.iter().copied(),
+                    let style = ShapeStyle::from(color).filled();
+                    let annotation = draw_series(
+                        &mut chart,
+                        axis,
+                        plotters::series::PointSeries::of_element(
+                            point_series
+                                .source
+                                .points(x_visible.clone())
+                                .iter()
+                                .copied()
+                                .filter(|point| visible && self.contains(*point, axis)),
                             5,
-                            ShapeStyle::from(&RED).filled(),
+                            style,
                             &|coord, size, style| {
                                 EmptyElement::at(coord) + Circle::new((0, 0), size, style)
                             },
+                        ),
+                    );
+
+                    if let (Some(label), Some((legend, size))) = (&point_series.label, legend) {
+                        let center = legend.glyph_width as i32 / 2;
+                        let glyph_style = ShapeStyle {
+                            color: style.color.mix(glyph_color),
+                            ..style
+                        };
+                        let entries = state.legend_entries.clone();
+
+                        annotation.label(label).legend(move |(x, y)| {
+                            entries.push(index, legend.entry_bounds((x, y), origin, size.0));
+
+                            Circle::new((x + center, y), 4, glyph_style)
+                        });
+                    }
+                }
+                Series::Bar(bar_series) => {
+                    let style = ShapeStyle::from(color).filled();
+                    let position = bar_groups.iter().position(|group| *group == index);
+                    let baseline = match axis {
+                        Axis::Primary => baselines.0,
+                        Axis::Secondary => baselines.1,
+                    };
+                    let annotation = draw_series(
+                        &mut chart,
+                        axis,
+                        position
+                            .into_iter()
+                            .flat_map(|position| {
+                                bar_series.bars(&slots, (position, bar_groups.len()), baseline)
+                            })
+                            .filter(|(top, _)| self.contains(*top, axis))
+                            .map(|corners| {
+                                plotters::element::Rectangle::new([corners.0, corners.1], style)
+                            }),
+                    );
+
+                    if let (Some(label), Some((legend, size))) = (&bar_series.label, legend) {
+                        let width = legend.glyph_width as i32;
+                        let glyph_style = ShapeStyle {
+                            color: style.color.mix(glyph_color),
+                            ..style
+                        };
+                        let entries = state.legend_entries.clone();
+
+                        annotation.label(label).legend(move |(x, y)| {
+                            entries.push(index, legend.entry_bounds((x, y), origin, size.0));
+
+                            plotters::element::Rectangle::new(
+                                [(x, y - 4), (x + width, y + 4)],
+                                glyph_style,
+                            )
+                        });
+                    }
+                }
+                Series::Boxplot(boxplot_series) => {
+                    let style = ShapeStyle::from(color);
+                    let fill = style.color.mix(0.3).filled();
+                    let position = distribution_groups.iter().position(|group| *group == index);
+                    let boxes: Vec<_> = position
+                        .into_iter()
+                        .flat_map(|position| {
+                            boxplot_series.boxes(
+                                &slots,
+                                (position, distribution_groups.len()),
+                                style,
+                            )
+                        })
+                        .filter(|element| {
+                            let summary = &element.summary;
+
+                            self.contains((element.center, summary.lower_whisker), axis)
+                                && self.contains((element.center, summary.upper_whisker), axis)
+                        })
+                        .collect();
+
+                    draw_series(
+                        &mut chart,
+                        axis,
+                        boxes.iter().map(|element| {
+                            plotters::element::Rectangle::new(element.corners(), fill)
+                        }),
+                    );
+                    draw_series::<_, boxplot::BoxElement, _, _>(&mut chart, axis, &boxes);
+
+                    let annotation = draw_series(
+                        &mut chart,
+                        axis,
+                        boxes
+                            .iter()
+                            .flat_map(|element| {
+                                let center = element.center;
+
+                                element
+                                    .summary
+                                    .outliers
+                                    .iter()
+                                    .map(move |outlier| (center, *outlier))
+                            })
+                            .filter(|point| self.contains(*point, axis))
+                            .map(|point| Circle::new(point, 3, style)),
+                    );
+
+                    if let (Some(label), Some((legend, size))) = (&boxplot_series.label, legend) {
+                        let width = legend.glyph_width as i32;
+                        let glyph_style = ShapeStyle {
+                            color: style.color.mix(glyph_color),
+                            ..style
+                        };
+                        let entries = state.legend_entries.clone();
+
+                        annotation.label(label).legend(move |(x, y)| {
+                            entries.push(index, legend.entry_bounds((x, y), origin, size.0));
+
+                            plotters::element::Rectangle::new(
+                                [(x, y - 4), (x + width, y + 4)],
+                                glyph_style,
+                            )
+                        });
+                    }
+                }
+                Series::Violin(violin_series) => {
+                    let style = ShapeStyle::from(color);
+                    let fill = style.color.mix(0.3).filled();
+                    let position = distribution_groups.iter().position(|group| *group == index);
+                    let violins: Vec<_> = position
+                        .into_iter()
+                        .flat_map(|position| {
+                            violin_series.violins(&slots, (position, distribution_groups.len()))
+                        })
+                        .filter(|violin| {
+                            violin
+                                .outline
+                                .iter()
+                                .all(|point| self.contains(*point, axis))
+                        })
+                        .collect();
+
+                    draw_series(
+                        &mut chart,
+                        axis,
+                        violins
+                            .iter()
+                            .map(|violin| Polygon::new(violin.outline.clone(), fill)),
+                    );
+
+                    let annotation = draw_series(
+                        &mut chart,
+                        axis,
+                        violins.iter().flat_map(|violin| {
+                            let mut closed = violin.outline.clone();
+                            closed.extend(violin.outline.first());
+
+                            [
+                                PathElement::new(closed, style),
+                                PathElement::new(violin.median.to_vec(), style),
+                            ]
+                        }),
+                    );
+
+                    if let (Some(label), Some((legend, size))) = (&violin_series.label, legend) {
+                        let width = legend.glyph_width as i32;
+                        let glyph_style = ShapeStyle {
+                            color: style.color.mix(glyph_color),
+                            ..style
+                        };
+                        let entries = state.legend_entries.clone();
+
+                        annotation.label(label).legend(move |(x, y)| {
+                            entries.push(index, legend.entry_bounds((x, y), origin, size.0));
+
+                            plotters::element::Rectangle::new(
+                                [(x, y - 4), (x + width, y + 4)],
+                                glyph_style.filled(),
+                            )
+                        });
+                    }
+                }
+                Series::Derived(derived_series) => {
+                    let style = ShapeStyle::from(color);
+                    let band_style = style.color.mix(0.2).filled();
+                    let (points, band) = if visible {
+                        (
+                            derived_series.data.downsampled(
+                                x_visible.clone(),
+                                columns,
+                                Downsample::default(),
+                            ),
+                            derived_series.band(),
+                        )
+                    } else {
+                        (Cow::Borrowed(&[][..]), Vec::new())
+                    };
+
+                    draw_series(
+                        &mut chart,
+                        axis,
+                        band.split(|&(x, lower, upper)| {
+                            !self.contains((x, lower), axis) || !self.contains((x, upper), axis)
+                        })
+                        .map(|part| {
+                            let upper = part.iter().map(|&(x, _, upper)| (x, upper));
+                            let lower = part.iter().rev().map(|&(x, lower, _)| (x, lower));
+
+                            Polygon::new(upper.chain(lower).collect::<Vec<_>>(), band_style)
+                        }),
+                    );
+
+                    let annotation = draw_series(
+                        &mut chart,
+                        axis,
+                        points
+                            .split(|point| !self.contains(*point, axis))
+                            .map(|segment| PathElement::new(segment.to_vec(), style)),
+                    );
+
+                    if let (Some(label), Some((legend, size))) = (&derived_series.label, legend) {
+                        let width = legend.glyph_width as i32;
+                        let glyph_style = ShapeStyle {
+                            color: style.color.mix(glyph_color),
+                            ..style
+                        };
+                        let entries = state.legend_entries.clone();
+
+                        annotation.label(label).legend(move |(x, y)| {
+                            entries.push(index, legend.entry_bounds((x, y), origin, size.0));
+
+                            PathElement::new(vec![(x, y), (x + width, y)], glyph_style)
+                        });
+                    }
+                }
+                Series::Heatmap(_) if !visible => {}
+                Series::Heatmap(heatmap_series) => {
+                    let range = heatmap_series.scaled_range();
+
+                    chart
+                        .draw_series(heatmap_series.cells().filter_map(
+                            |(upper_left, bottom_right, value)| {
+                                if !self.contains(upper_left, axis)
+                                    || !self.contains(bottom_right, axis)
+                                {
+                                    return None;
+                                }
+
+                                let color = heatmap_series.color(value, &range)?;
+
+                                Some(plotters::element::Rectangle::new(
+                                    [upper_left, bottom_right],
+                                    ShapeStyle::from(color).filled(),
+                                ))
+                            },
                         ))
                         .unwrap();
                 }
             }
+
+            let (source, errors) = match s {
+                _ if !visible => continue,
+                Series::Line(line_series) => (&line_series.source, &line_series.errors),
+                Series::Point(point_series) => (&point_series.source, &point_series.errors),
+                Series::Bar(_)
+                | Series::Boxplot(_)
+                | Series::Violin(_)
+                | Series::Derived(_)
+                | Series::Heatmap(_) => continue,
+            };
+
+            if !errors.is_empty() {
+                let style = ShapeStyle::from(color).stroke_width(1);
+                // Errors belong to points by index, counted from the first visible one
+                let (first, data) = source.indexed_points(x_visible.clone());
+
+                draw_series(
+                    &mut chart,
+                    axis,
+                    data.iter()
+                        .zip(errors.y.iter().skip(first))
+                        .filter(|(point, _)| self.contains(**point, axis))
+                        .map(|(&(x, y), error)| {
+                            ErrorBar::new_vertical(
+                                x,
+                                y - error.minus.abs(),
+                                y,
+                                y + error.plus.abs(),
+                                style,
+                                errors.whisker_width,
+                            )
+                        }),
+                );
+
+                draw_series(
+                    &mut chart,
+                    axis,
+                    data.iter()
+                        .zip(errors.x.iter().skip(first))
+                        .filter(|(point, _)| self.contains(**point, axis))
+                        .map(|(&(x, y), error)| {
+                            ErrorBar::new_horizontal(
+                                y,
+                                x - error.minus.abs(),
+                                x,
+                                x + error.plus.abs(),
+                                style,
+                                errors.whisker_width,
+                            )
+                        }),
+                );
+            }
+        }
+
+        if let Some((legend, size)) = legend {
+            let background = Color(legend.background.unwrap_or(style.legend.background));
+            let border = Color(legend.border.unwrap_or(style.legend.border));
+            let font = ("sans-serif", Legend::FONT_SIZE)
+                .into_font()
+                .color(&RGBAColor::from(Color(style.legend.text)));
+            let position = legend.series_label_position(
+                size,
+                chart.plotting_area().get_pixel_range(),
+                bounds.size(),
+                self.margin,
+            );
+
+            chart
+                .configure_series_labels()
+                .position(position)
+                .margin(legend.padding)
+                .legend_area_size(legend.glyph_width)
+                .background_style(RGBAColor::from(background).filled())
+                .border_style(RGBAColor::from(border).stroke_width(1))
+                .label_font(font)
+                .draw()
+                .unwrap();
         }
     }
 
     fn update(
         &self,
-        _state: &mut Self::State,
+        state: &mut Self::State,
         event: event::Event,
         bounds: Rectangle,
         cursor: iced::mouse::Cursor,
     ) -> (event::Status, Option<Message>) {
-        let x_range = match self.x_range.clone() {
-            AxisRange::Custom(x_range) => x_range,
-            AxisRange::Automatic(Some(x_range)) => x_range,
-            AxisRange::Automatic(None) => Attributes::<Message>::X_RANGE_DEFAULT,
-        };
+        if let event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event
+            && let Some(index) = cursor
+                .position_in(bounds)
+                .and_then(|position| state.legend_entries.find(position))
+        {
+            let position = cursor.position().unwrap_or_default();
+            let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+            state.last_click = Some(click);
+
+            let legend_event = match click.kind() {
+                mouse::click::Kind::Single => LegendEvent::Toggled(index),
+                _ => LegendEvent::Isolated(index),
+            };
 
-        let y_range = match self.y_range.clone() {
-            AxisRange::Custom(y_range) => y_range,
-            AxisRange::Automatic(Some(y_range)) => y_range,
-            AxisRange::Automatic(None) => Attributes::<Message>::Y_RANGE_DEFAULT,
-        };
+            if let Some(on_series_toggle) = self.on_series_toggle.as_ref() {
+                return (
+                    event::Status::Captured,
+                    Some(on_series_toggle(legend_event)),
+                );
+            }
 
-        let coord_spec: Cartesian2d<RangedCoordf32, RangedCoordf32> = Cartesian2d::new(
-            x_range,
-            y_range,
-            (0..bounds.width as i32, 0..bounds.height as i32),
-        );
+            self.on_legend_click(state, legend_event);
+
+            return (event::Status::Captured, None);
+        }
+
+        let cartesian = state.coord_spec.borrow().clone().unwrap_or_else(|| {
+            let (x_range, y_range, secondary_y_range) = self.ranges(state);
+            let pixels = (0..bounds.width as i32, bounds.height as i32..0);
+
+            Cartesian::scaled(
+                Cartesian2d::new(
+                    self.x_coord(x_range.clone()),
+                    self.y_coord(y_range, Axis::Primary),
+                    pixels.clone(),
+                ),
+                self.has_secondary_axis().then(|| {
+                    Cartesian2d::new(
+                        self.x_coord(x_range),
+                        self.y_coord(secondary_y_range, Axis::Secondary),
+                        pixels,
+                    )
+                }),
+            )
+        });
 
         if let Some(on_scroll) = self.on_scroll.as_ref() {
             if let event::Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
@@ -552,7 +1707,7 @@ where
 
                 return (
                     event::Status::Captured,
-                    Some(on_scroll(position, delta, Cartesian::new(coord_spec))),
+                    Some(on_scroll(position, delta, cartesian)),
                 );
             }
         }
@@ -563,10 +1718,7 @@ where
                 let position = position - origin;
                 let position = iced::Point::new(position.x, position.y);
 
-                return (
-                    event::Status::Captured,
-                    Some(on_move(position, Cartesian::new(coord_spec))),
-                );
+                return (event::Status::Captured, Some(on_move(position, cartesian)));
             }
         }
 
@@ -575,11 +1727,30 @@ where
 
     fn mouse_interaction(
         &self,
-        _state: &Self::State,
-        _bounds: Rectangle,
-        _cursor: iced::mouse::Cursor,
+        state: &Self::State,
+        bounds: Rectangle,
+        cursor: iced::mouse::Cursor,
     ) -> iced::mouse::Interaction {
-        iced::mouse::Interaction::default()
+        let over_legend = cursor
+            .position_in(bounds)
+            .and_then(|position| state.legend_entries.find(position))
+            .is_some();
+
+        if over_legend {
+            iced::mouse::Interaction::Pointer
+        } else {
+            iced::mouse::Interaction::default()
+        }
+    }
+
+    fn data_version(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        for s in &self.series {
+            s.version().hash(&mut hasher);
+        }
+
+        hasher.finish()
     }
 }
 
@@ -587,26 +1758,208 @@ where
 pub enum Series {
     Line(LineSeries),
     Point(PointSeries),
+    Bar(BarSeries),
+    Boxplot(BoxplotSeries),
+    Violin(ViolinSeries),
+    Derived(DerivedSeries),
+    Heatmap(HeatmapSeries),
+}
+
+impl Series {
+    fn axis(&self) -> Axis {
+        match self {
+            Series::Line(line_series) => line_series.axis,
+            Series::Point(point_series) => point_series.axis,
+            Series::Bar(bar_series) => bar_series.axis,
+            Series::Boxplot(boxplot_series) => boxplot_series.axis,
+            Series::Violin(violin_series) => violin_series.axis,
+            Series::Derived(derived_series) => derived_series.axis,
+            Series::Heatmap(_) => Axis::Primary,
+        }
+    }
+
+    /// Returns the version of the [`DataSource`] of the series, or `0` if it is not tracked.
+    fn version(&self) -> u64 {
+        match self {
+            Series::Line(line_series) => line_series.source.version(),
+            Series::Point(point_series) => point_series.source.version(),
+            Series::Bar(_)
+            | Series::Boxplot(_)
+            | Series::Violin(_)
+            | Series::Derived(_)
+            | Series::Heatmap(_) => 0,
+        }
+    }
+
+    fn label(&self) -> Option<&str> {
+        match self {
+            Series::Line(line_series) => line_series.label.as_deref(),
+            Series::Point(point_series) => point_series.label.as_deref(),
+            Series::Bar(bar_series) => bar_series.label.as_deref(),
+            Series::Boxplot(boxplot_series) => boxplot_series.label.as_deref(),
+            Series::Violin(violin_series) => violin_series.label.as_deref(),
+            Series::Derived(derived_series) => derived_series.label.as_deref(),
+            Series::Heatmap(_) => None,
+        }
+    }
+
+    /// Returns the x range covered by the series, including its error bars.
+    fn x_bounds(&self) -> Extent {
+        let (source, errors) = match self {
+            Series::Line(line_series) => (&line_series.source, &line_series.errors),
+            Series::Point(point_series) => (&point_series.source, &point_series.errors),
+            // Bars, boxes and violins are placed in the slots of their categories
+            Series::Bar(_) | Series::Boxplot(_) | Series::Violin(_) => return Extent::EMPTY,
+            Series::Derived(derived_series) => return derived_series.x_bounds(),
+            Series::Heatmap(heatmap_series) => return heatmap_series.x_bounds(),
+        };
+
+        errors.x_bounds(source.bounds().0)
+    }
+
+    /// Returns the y range covered by a line or point series while the x axis shows `x_range`,
+    /// or `None` for other series.
+    fn y_bounds_within(&self, x_range: Range<f64>) -> Option<Extent> {
+        let (source, errors) = match self {
+            Series::Line(line_series) => (&line_series.source, &line_series.errors),
+            Series::Point(point_series) => (&point_series.source, &point_series.errors),
+            _ => return None,
+        };
+
+        Some(errors.y_bounds(source.y_bounds(x_range)))
+    }
+
+    /// Returns the y range covered by the series, including its error bars.
+    fn y_bounds(&self) -> Extent {
+        let (source, errors) = match self {
+            Series::Line(line_series) => (&line_series.source, &line_series.errors),
+            Series::Point(point_series) => (&point_series.source, &point_series.errors),
+            Series::Bar(bar_series) => return bar_series.y_bounds(),
+            Series::Boxplot(boxplot_series) => return boxplot_series.y_bounds(),
+            Series::Violin(violin_series) => return violin_series.y_bounds(),
+            Series::Derived(derived_series) => return derived_series.y_bounds(),
+            Series::Heatmap(heatmap_series) => return heatmap_series.y_bounds(),
+        };
+
+        errors.y_bounds(source.bounds().1)
+    }
 }
 
 #[derive(Clone)]
 pub struct LineSeries {
-    pub data: Vec<(f32, f32)>,
-    pub color: Color,
+    /// The points of the line, which is broken at NaN values.
+    pub source: Rc<dyn DataSource>,
+    /// The color of the series, or `None` to pick one from the palette of the chart.
+    pub color: Option<Color>,
+    pub errors: ErrorBars,
+    pub label: Option<String>,
+    pub visible: bool,
+    pub axis: Axis,
+    /// The largest x distance between two points connected by the line.
+    pub max_gap: Option<f64>,
+    /// How the visible part of the line is reduced when it has more points than pixels.
+    pub downsample: Downsample,
 }
 
 impl LineSeries {
-    pub fn new(iter: impl IntoIterator<Item = (f32, f32)>) -> Self {
+    pub fn new(iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>) -> Self {
         Self {
-            data: iter.into_iter().collect(),
-            color: Color(iced::Color::BLACK),
+            source: Rc::new(Data::untracked(iter)),
+            color: None,
+            errors: ErrorBars::default(),
+            label: None,
+            visible: true,
+            axis: Axis::Primary,
+            max_gap: None,
+            downsample: Downsample::default(),
+        }
+    }
+
+    /// Creates a series drawing the points of `source`, like shared [`Data`].
+    pub fn from_source(source: impl DataSource + 'static) -> Self {
+        Self {
+            source: Rc::new(source),
+            ..Self::new(std::iter::empty::<(f64, f64)>())
         }
     }
 
     pub fn color(mut self, color: impl Into<Color>) -> Self {
-        self.color = color.into();
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the label of the series shown in the legend.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the series is drawn and included in automatic ranges.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the y axis the series is drawn against.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Breaks the line between two points further apart than `max_gap` on the x axis.
+    pub fn max_gap(mut self, max_gap: impl AxisValue) -> Self {
+        self.max_gap = Some(max_gap.to_f64());
+        self
+    }
+
+    /// Sets how the line is reduced when it has more visible points than pixels, sorted by x.
+    pub fn downsample(mut self, downsample: Downsample) -> Self {
+        self.downsample = downsample;
+        self
+    }
+
+    /// Iterates over the connected parts of a line through `points`, broken at values which are
+    /// not finite, like `None`, and at gaps wider than [`max_gap`](Self::max_gap).
+    pub fn segments<'a>(&self, points: &'a [(f64, f64)]) -> impl Iterator<Item = &'a [(f64, f64)]> {
+        self.drawn_segments(points, |(x, y)| x.is_finite() && y.is_finite())
+    }
+
+    /// Iterates over the connected parts of the line, broken at points which are not
+    /// `drawable` and at gaps.
+    fn drawn_segments<'a>(
+        &self,
+        points: &'a [(f64, f64)],
+        drawable: impl Fn((f64, f64)) -> bool,
+    ) -> impl Iterator<Item = &'a [(f64, f64)]> {
+        let max_gap = self.max_gap.unwrap_or(f64::INFINITY);
+
+        points
+            .split(move |point| !drawable(*point))
+            .flat_map(move |segment| segment.chunk_by(move |a, b| (b.0 - a.0).abs() <= max_gap))
+    }
+
+    /// Sets the x errors, one per data point.
+    pub fn x_errors(mut self, iter: impl IntoIterator<Item = impl Into<ErrorValue>>) -> Self {
+        self.errors.x = iter.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the y errors, one per data point.
+    pub fn y_errors(mut self, iter: impl IntoIterator<Item = impl Into<ErrorValue>>) -> Self {
+        self.errors.y = iter.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the width of the error bar whiskers in pixels.
+    pub fn whisker_width(mut self, width: u32) -> Self {
+        self.errors.whisker_width = width;
         self
     }
+
+    /// Computes a series from the points of this one, drawn against the same y axis.
+    pub fn derive(&self, derivation: Derivation) -> DerivedSeries {
+        DerivedSeries::new(self.source.as_ref(), derivation).axis(self.axis)
+    }
 }
 
 impl From<LineSeries> for Series {
@@ -617,22 +1970,80 @@ impl From<LineSeries> for Series {
 
 #[derive(Clone)]
 pub struct PointSeries {
-    pub data: Vec<(f32, f32)>,
-    pub color: Color,
+    pub source: Rc<dyn DataSource>,
+    /// The color of the series, or `None` to pick one from the palette of the chart.
+    pub color: Option<Color>,
+    pub errors: ErrorBars,
+    pub label: Option<String>,
+    pub visible: bool,
+    pub axis: Axis,
 }
 
 impl PointSeries {
-    pub fn new(iter: impl IntoIterator<Item = (f32, f32)>) -> Self {
+    pub fn new(iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>) -> Self {
+        Self {
+            source: Rc::new(Data::untracked(iter)),
+            color: None,
+            errors: ErrorBars::default(),
+            label: None,
+            visible: true,
+            axis: Axis::Primary,
+        }
+    }
+
+    /// Creates a series drawing the points of `source`, like shared [`Data`].
+    pub fn from_source(source: impl DataSource + 'static) -> Self {
         Self {
-            data: iter.into_iter().collect(),
-            color: Color(iced::Color::BLACK),
+            source: Rc::new(source),
+            ..Self::new(std::iter::empty::<(f64, f64)>())
         }
     }
 
     pub fn color(mut self, color: impl Into<Color>) -> Self {
-        self.color = color.into();
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the label of the series shown in the legend.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the series is drawn and included in automatic ranges.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the y axis the series is drawn against.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the x errors, one per data point.
+    pub fn x_errors(mut self, iter: impl IntoIterator<Item = impl Into<ErrorValue>>) -> Self {
+        self.errors.x = iter.into_iter().map(Into::into).collect();
         self
     }
+
+    /// Sets the y errors, one per data point.
+    pub fn y_errors(mut self, iter: impl IntoIterator<Item = impl Into<ErrorValue>>) -> Self {
+        self.errors.y = iter.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the width of the error bar whiskers in pixels.
+    pub fn whisker_width(mut self, width: u32) -> Self {
+        self.errors.whisker_width = width;
+        self
+    }
+
+    /// Computes a series from the points of this one, drawn against the same y axis.
+    pub fn derive(&self, derivation: Derivation) -> DerivedSeries {
+        DerivedSeries::new(self.source.as_ref(), derivation).axis(self.axis)
+    }
 }
 
 impl From<PointSeries> for Series {
@@ -641,21 +2052,60 @@ impl From<PointSeries> for Series {
     }
 }
 
-pub fn line_series(iter: impl IntoIterator<Item = (f32, f32)>) -> LineSeries {
+impl From<BarSeries> for Series {
+    fn from(bar_series: BarSeries) -> Self {
+        Self::Bar(bar_series)
+    }
+}
+
+impl From<BoxplotSeries> for Series {
+    fn from(boxplot_series: BoxplotSeries) -> Self {
+        Self::Boxplot(boxplot_series)
+    }
+}
+
+impl From<ViolinSeries> for Series {
+    fn from(violin_series: ViolinSeries) -> Self {
+        Self::Violin(violin_series)
+    }
+}
+
+impl From<DerivedSeries> for Series {
+    fn from(derived_series: DerivedSeries) -> Self {
+        Self::Derived(derived_series)
+    }
+}
+
+impl From<HeatmapSeries> for Series {
+    fn from(heatmap_series: HeatmapSeries) -> Self {
+        Self::Heatmap(heatmap_series)
+    }
+}
+
+pub fn line_series(iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>) -> LineSeries {
     LineSeries::new(iter)
 }
 
-pub fn point_series(iter: impl IntoIterator<Item = (f32, f32)>) -> PointSeries {
+pub fn point_series(
+    iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>,
+) -> PointSeries {
     PointSeries::new(iter)
 }
 
-impl<Backend> From<&LineSeries> for plotters::series::LineSeries<Backend, (f32, f32)>
+impl<Backend> From<&LineSeries> for plotters::series::LineSeries<Backend, (f64, f64)>
 where
     Backend: plotters::backend::DrawingBackend,
 {
     fn from(series: &LineSeries) -> Self {
-        let style: ShapeStyle = series.color.into();
-        Self::new(series.data.clone(), style)
+        let style: ShapeStyle = series.color.unwrap_or(Color(iced::Color::BLACK)).into();
+        Self::new(
+            series
+                .source
+                .points(f64::NEG_INFINITY..f64::INFINITY)
+                .iter()
+                .copied(),
+            style,
+        )
     }
 }
 
@@ -0,0 +1,243 @@
+use super::AutoRange;
+use super::scale::{Scale, ScaledRange};
+
+use std::fmt;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// The automatic range, ticks, labels and line of an axis of a [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone)]
+pub struct AxisOptions {
+    pub auto_range: AutoRange,
+    pub format: TickFormat,
+    pub ticks: Ticks,
+    /// Appended to every tick label, after SI prefixes.
+    pub unit: Option<String>,
+    /// The maximum number of minor divisions between two ticks, or `None` for the default.
+    pub minor_ticks: Option<usize>,
+    pub labels: bool,
+    pub visible: bool,
+}
+
+impl Default for AxisOptions {
+    fn default() -> Self {
+        Self {
+            auto_range: AutoRange::default(),
+            format: TickFormat::default(),
+            ticks: Ticks::default(),
+            unit: None,
+            minor_ticks: None,
+            labels: true,
+            visible: true,
+        }
+    }
+}
+
+impl AxisOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the range is fitted to the data when no range is set on the [`Chart`].
+    ///
+    /// [`Chart`]: crate::Chart
+    pub fn auto_range(mut self, auto_range: AutoRange) -> Self {
+        self.auto_range = auto_range;
+        self
+    }
+
+    pub fn format(mut self, format: TickFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Formats tick labels with a fixed number of decimals.
+    pub fn decimals(self, decimals: usize) -> Self {
+        self.format(TickFormat::Decimals(decimals))
+    }
+
+    /// Formats tick labels with SI prefixes, like `1.5 k` or `20 µ`.
+    pub fn si(self) -> Self {
+        self.format(TickFormat::Si)
+    }
+
+    /// Formats fractions as percentages, `0.25` becomes `25%`.
+    pub fn percent(self) -> Self {
+        self.format(TickFormat::Percent)
+    }
+
+    pub fn formatter(self, formatter: impl Fn(f64) -> String + 'static) -> Self {
+        self.format(TickFormat::Custom(Formatter(Rc::new(formatter))))
+    }
+
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    pub fn ticks(mut self, ticks: Ticks) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Sets the number of ticks plotters aims for.
+    pub fn tick_count(self, count: usize) -> Self {
+        self.ticks(Ticks::Count(count))
+    }
+
+    /// Places a tick at every multiple of `step`.
+    pub fn tick_step(self, step: f64) -> Self {
+        self.ticks(Ticks::Step(step))
+    }
+
+    /// Places ticks at exactly the given values.
+    pub fn tick_values(self, values: impl IntoIterator<Item = f64>) -> Self {
+        self.ticks(Ticks::Values(values.into_iter().collect()))
+    }
+
+    pub fn minor_ticks(mut self, divisions: usize) -> Self {
+        self.minor_ticks = Some(divisions);
+        self
+    }
+
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets whether the axis line and its labels are drawn.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Returns the major ticks within `range` and their labels, as drawn on an axis with
+    /// `scale`.
+    pub fn tick_labels(&self, range: Range<f64>, scale: Scale) -> Vec<(f64, String)> {
+        ScaledRange::new(range, scale.validated())
+            .with_ticks(self.ticks.clone())
+            .labels(self)
+    }
+
+    /// Returns whether tick labels are drawn.
+    pub(crate) fn has_labels(&self) -> bool {
+        self.labels && self.visible
+    }
+
+    /// Returns the number of ticks requested from the coordinate.
+    pub(crate) fn tick_count_hint(&self, default: usize) -> usize {
+        match self.ticks {
+            Ticks::Count(count) => count,
+            Ticks::Auto | Ticks::Step(_) | Ticks::Values(_) => default,
+        }
+    }
+
+    /// Formats a tick label, falling back to `default` for [`TickFormat::Default`].
+    pub(crate) fn label(&self, value: f64, default: impl Fn(f64) -> String) -> String {
+        let label = match &self.format {
+            TickFormat::Default => default(value),
+            TickFormat::Decimals(decimals) => format!("{value:.decimals$}"),
+            TickFormat::Si => {
+                let (value, prefix) = si_prefix(value);
+
+                return match (prefix, &self.unit) {
+                    ("", None) => trimmed(value),
+                    (prefix, unit) => {
+                        format!(
+                            "{} {prefix}{}",
+                            trimmed(value),
+                            unit.as_deref().unwrap_or("")
+                        )
+                    }
+                };
+            }
+            TickFormat::Percent => format!("{}%", trimmed(value * 100.0)),
+            TickFormat::Custom(formatter) => (formatter.0)(value),
+        };
+
+        match &self.unit {
+            Some(unit) => format!("{label} {unit}"),
+            None => label,
+        }
+    }
+}
+
+/// The y axis a series is drawn against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Axis {
+    /// The y axis on the left.
+    #[default]
+    Primary,
+    /// The y axis on the right, with its own range and scale.
+    Secondary,
+}
+
+/// How the tick labels of an axis are formatted.
+#[derive(Debug, Clone, Default)]
+pub enum TickFormat {
+    /// The formatting of plotters, aware of the [`Scale`](crate::Scale) of the axis.
+    #[default]
+    Default,
+    Decimals(usize),
+    Si,
+    Percent,
+    Custom(Formatter),
+}
+
+/// A closure formatting tick labels.
+#[derive(Clone)]
+pub struct Formatter(pub Rc<dyn Fn(f64) -> String>);
+
+impl fmt::Debug for Formatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Formatter")
+    }
+}
+
+/// Where the ticks of an axis are placed.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Ticks {
+    /// Chosen by the [`Scale`](crate::Scale) of the axis.
+    #[default]
+    Auto,
+    /// Chosen by the scale, aiming for the given number of ticks.
+    Count(usize),
+    Step(f64),
+    Values(Vec<f64>),
+}
+
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// Scales `value` into `1..1000` and returns the matching prefix.
+fn si_prefix(value: f64) -> (f64, &'static str) {
+    if value == 0.0 || !value.is_finite() {
+        return (value, "");
+    }
+
+    let mut exponent = ((value.abs().log10() / 3.0).floor() as i32).clamp(-8, 8);
+
+    // 999.9999999 is shown as 1 k rather than 1000
+    if trimmed(value.abs() / 1000f64.powi(exponent)) == "1000" && exponent < 8 {
+        exponent += 1;
+    }
+
+    (
+        value / 1000f64.powi(exponent),
+        SI_PREFIXES[(exponent + 8) as usize],
+    )
+}
+
+/// Formats `value` with up to six decimals, without trailing zeros.
+fn trimmed(value: f64) -> String {
+    let formatted = format!("{value:.6}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    match formatted {
+        "-0" => "0".to_string(),
+        formatted => formatted.to_string(),
+    }
+}
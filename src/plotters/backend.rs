@@ -5,19 +5,11 @@ use iced::advanced::text::{self, Alignment, Paragraph as _};
 use iced::alignment::{Horizontal, Vertical};
 use iced::widget::canvas;
 use iced::widget::text::Shaping;
-use iced::{Font, Pixels, Size, font};
+use iced::{Font, Pixels, Point, Size, Vector, font};
 
 use plotters_backend::{
-    //FontTransform,
-    BackendColor,
-    BackendCoord,
-    BackendStyle,
-    BackendTextStyle,
-    DrawingBackend,
-    DrawingErrorKind,
-    FontFamily,
-    FontStyle,
-    text_anchor,
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+    FontFamily, FontStyle, FontTransform, text_anchor,
 };
 
 use std::collections::BTreeSet;
@@ -244,25 +236,26 @@ where
             align_y,
             shaping: self.shaping,
         };
-        //TODO: fix rotation until text rotation is supported by Iced
-        // let rotate = match style.transform() {
-        //     FontTransform::None => None,
-        //     FontTransform::Rotate90 => Some(90.0),
-        //     FontTransform::Rotate180 => Some(180.0),
-        //     FontTransform::Rotate270 => Some(270.0),
-        //     FontTransform::RotateAngle(angle) => Some(angle),
-        // };
-        // if let Some(rotate) = rotate {
-        //     dbg!(rotate);
-        //     self.frame.with_save(move |frame| {
-        //         frame.fill_text(text);
-        //         frame.translate(Vector::new(pos.x + w as f32 / 2.0, pos.y + h as f32 / 2.0));
-        //         let angle = 2.0 * std::f32::consts::PI * rotate / 360.0;
-        //         frame.rotate(angle);
-        //     });
-        // } else {
-        //     self.frame.fill_text(text);
-        // }
+        let angle = match style.transform() {
+            FontTransform::None => None,
+            FontTransform::Rotate90 => Some(90.0_f32),
+            FontTransform::Rotate180 => Some(180.0),
+            FontTransform::Rotate270 => Some(270.0),
+        };
+
+        if let Some(angle) = angle {
+            self.frame.with_save(move |frame| {
+                frame.translate(Vector::new(pos.x, pos.y));
+                frame.rotate(angle.to_radians());
+                frame.fill_text(canvas::Text {
+                    position: Point::ORIGIN,
+                    ..text
+                });
+            });
+
+            return Ok(());
+        }
+
         self.frame.fill_text(text);
 
         Ok(())
@@ -274,31 +267,14 @@ where
         text: &str,
         style: &S,
     ) -> Result<(u32, u32), DrawingErrorKind<Self::ErrorType>> {
-        let font = style_to_font(style);
-        let bounds = self.frame.size();
-        let align_x = match style.anchor().h_pos {
-            text_anchor::HPos::Left => Alignment::Left,
-            text_anchor::HPos::Center => Alignment::Center,
-            text_anchor::HPos::Right => Alignment::Right,
-        };
-        let align_y = match style.anchor().v_pos {
-            text_anchor::VPos::Top => Vertical::Top,
-            text_anchor::VPos::Center => Vertical::Center,
-            text_anchor::VPos::Bottom => Vertical::Bottom,
-        };
+        let size = measure_text(
+            text,
+            style,
+            self.default_font_size,
+            self.shaping,
+            self.frame.size(),
+        );
 
-        let p = graphics::text::Paragraph::with_text(text::Text {
-            content: text,
-            bounds,
-            size: self.default_font_size,
-            line_height: Default::default(),
-            font,
-            align_x,
-            align_y,
-            shaping: self.shaping,
-            wrapping: text::Wrapping::Word,
-        });
-        let size = p.min_bounds();
         Ok((size.width as u32, size.height as u32))
     }
 
@@ -316,6 +292,40 @@ where
     }
 }
 
+/// Measures the size of `text` drawn with `style` and `size`, wrapping at `bounds`.
+pub(crate) fn measure_text<S: BackendTextStyle>(
+    text: &str,
+    style: &S,
+    size: Pixels,
+    shaping: Shaping,
+    bounds: Size,
+) -> Size {
+    let align_x = match style.anchor().h_pos {
+        text_anchor::HPos::Left => Alignment::Left,
+        text_anchor::HPos::Center => Alignment::Center,
+        text_anchor::HPos::Right => Alignment::Right,
+    };
+    let align_y = match style.anchor().v_pos {
+        text_anchor::VPos::Top => Vertical::Top,
+        text_anchor::VPos::Center => Vertical::Center,
+        text_anchor::VPos::Bottom => Vertical::Bottom,
+    };
+
+    let p = graphics::text::Paragraph::with_text(text::Text {
+        content: text,
+        bounds,
+        size,
+        line_height: Default::default(),
+        font: style_to_font(style),
+        align_x,
+        align_y,
+        shaping,
+        wrapping: text::Wrapping::Word,
+    });
+
+    p.min_bounds()
+}
+
 fn style_to_font<S: BackendTextStyle>(style: &S) -> Font {
     static FONTS: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
 
@@ -0,0 +1,97 @@
+use super::scale::Extent;
+use super::{Axis, AxisValue, Color};
+
+use std::collections::HashMap;
+
+/// Values per category, drawn as bars on a categorical x axis.
+///
+/// Bars of several visible series are grouped side by side within the slot of their category.
+#[derive(Clone)]
+pub struct BarSeries {
+    pub data: Vec<(String, f64)>,
+    /// The color of the series, or `None` to pick one from the palette of the chart.
+    pub color: Option<Color>,
+    pub label: Option<String>,
+    pub visible: bool,
+    pub axis: Axis,
+    /// The share of a category slot covered by the bars of all series, between 0 and 1.
+    pub width: f64,
+}
+
+impl BarSeries {
+    pub fn new(iter: impl IntoIterator<Item = (impl Into<String>, impl AxisValue)>) -> Self {
+        Self {
+            data: iter
+                .into_iter()
+                .map(|(category, value)| (category.into(), value.to_f64()))
+                .collect(),
+            color: None,
+            label: None,
+            visible: true,
+            axis: Axis::Primary,
+            width: 0.8,
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the label of the series shown in the legend.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the series is drawn and included in automatic ranges.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the y axis the series is drawn against.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub(crate) fn categories(&self) -> impl Iterator<Item = &str> {
+        self.data.iter().map(|(category, _)| category.as_str())
+    }
+
+    /// Returns the y range covered by the bars, which always start at zero.
+    pub(crate) fn y_bounds(&self) -> Extent {
+        self.data.iter().fold(Extent::EMPTY, |extent, &(_, value)| {
+            extent.include(value.min(0.0)..value.max(0.0))
+        })
+    }
+
+    /// Iterates over the corners of every bar, being the `position`th of `count` bars per slot.
+    pub(crate) fn bars<'a>(
+        &'a self,
+        slots: &'a HashMap<&str, usize>,
+        (position, count): (usize, usize),
+        baseline: f64,
+    ) -> impl Iterator<Item = ((f64, f64), (f64, f64))> + 'a {
+        let width = self.width.clamp(0.0, 1.0) / count.max(1) as f64;
+        let offset = (1.0 - self.width.clamp(0.0, 1.0)) / 2.0 + width * position as f64;
+
+        self.data.iter().filter_map(move |(category, value)| {
+            let left = *slots.get(category.as_str())? as f64 + offset;
+
+            Some(((left, *value), (left + width, baseline)))
+        })
+    }
+}
+
+pub fn bar_series(
+    iter: impl IntoIterator<Item = (impl Into<String>, impl AxisValue)>,
+) -> BarSeries {
+    BarSeries::new(iter)
+}
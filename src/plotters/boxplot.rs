@@ -0,0 +1,319 @@
+use super::scale::Extent;
+use super::{Axis, AxisValue, Color};
+
+use plotters::data::Quartiles;
+use plotters::element::{Boxplot, Drawable, PointCollection};
+use plotters::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+use std::collections::HashMap;
+
+/// How far the whiskers of a box plot reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Whiskers {
+    /// To the most extreme samples within 1.5 interquartile ranges of the box. The samples
+    /// beyond are outliers.
+    #[default]
+    Tukey,
+    /// To the smallest and the largest sample.
+    MinMax,
+}
+
+/// The distribution of the samples of a category.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxSummary {
+    pub count: usize,
+    pub lower_whisker: f64,
+    pub lower_quartile: f64,
+    pub median: f64,
+    pub upper_quartile: f64,
+    pub upper_whisker: f64,
+    /// The samples beyond the whiskers, in ascending order.
+    pub outliers: Vec<f64>,
+}
+
+impl BoxSummary {
+    /// Summarizes the finite `samples`, or returns `None` if there are none.
+    ///
+    /// The quartiles are interpolated between samples like the [`Quartiles`] of plotters.
+    pub fn new(samples: &[f64], whiskers: Whiskers) -> Option<Self> {
+        Self::with_quartiles(samples, whiskers).map(|(summary, _)| summary)
+    }
+
+    fn with_quartiles(samples: &[f64], whiskers: Whiskers) -> Option<(Self, Quartiles)> {
+        let mut sorted: Vec<f64> = samples
+            .iter()
+            .copied()
+            .filter(|sample| sample.is_finite())
+            .collect();
+        sorted.sort_by(f64::total_cmp);
+
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        let lower_quartile = percentile(&sorted, 0.25);
+        let upper_quartile = percentile(&sorted, 0.75);
+
+        let (lower_whisker, upper_whisker) = match whiskers {
+            Whiskers::Tukey => {
+                let iqr = upper_quartile - lower_quartile;
+                let lower_fence = lower_quartile - 1.5 * iqr;
+                let upper_fence = upper_quartile + 1.5 * iqr;
+                let last = sorted.len() - 1;
+
+                // plotters places the whiskers at the fences, Tukey at the last samples within
+                let lower = sorted.partition_point(|sample| *sample < lower_fence);
+                let upper = sorted.partition_point(|sample| *sample <= upper_fence);
+
+                (
+                    sorted[lower.min(last)].min(lower_quartile),
+                    sorted[upper.saturating_sub(1)].max(upper_quartile),
+                )
+            }
+            Whiskers::MinMax => (min, max),
+        };
+
+        let summary = Self {
+            count: sorted.len(),
+            lower_whisker,
+            lower_quartile,
+            median: percentile(&sorted, 0.5),
+            upper_quartile,
+            upper_whisker,
+            outliers: sorted
+                .iter()
+                .copied()
+                .filter(|sample| *sample < lower_whisker || *sample > upper_whisker)
+                .collect(),
+        };
+
+        // Only drawing uses the quartiles of plotters, the element hands over its own values
+        Some((summary, Quartiles::new(&sorted)))
+    }
+}
+
+/// Returns the value at `fraction` of the `sorted` samples, interpolating linearly between the
+/// closest two.
+pub(crate) fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let rank = fraction * (sorted.len() - 1) as f64;
+    let index = rank.floor() as usize;
+
+    match sorted.get(index + 1) {
+        Some(next) => sorted[index] + (next - sorted[index]) * (rank - index as f64),
+        None => sorted[index],
+    }
+}
+
+/// A box in chart coordinates, drawn by the [`Boxplot`] element of plotters.
+///
+/// [`Boxplot`] is placed in `f32` values and sized in pixels, so this element maps the `f64`
+/// values of the summary and the width of the box itself before handing the pixels over.
+pub(crate) struct BoxElement {
+    pub summary: BoxSummary,
+    pub center: f64,
+    pub half_width: f64,
+    quartiles: Quartiles,
+    style: ShapeStyle,
+}
+
+impl BoxElement {
+    /// Returns the corners of the box from the lower to the upper quartile.
+    pub fn corners(&self) -> [(f64, f64); 2] {
+        [
+            (self.center - self.half_width, self.summary.upper_quartile),
+            (self.center + self.half_width, self.summary.lower_quartile),
+        ]
+    }
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a BoxElement {
+    type Point = (f64, f64);
+    type IntoIter = [(f64, f64); 7];
+
+    fn point_iter(self) -> Self::IntoIter {
+        let summary = &self.summary;
+
+        // The order of the values of `Quartiles`, followed by both edges of the box
+        [
+            (self.center, summary.lower_whisker),
+            (self.center, summary.lower_quartile),
+            (self.center, summary.median),
+            (self.center, summary.upper_quartile),
+            (self.center, summary.upper_whisker),
+            (self.center - self.half_width, summary.median),
+            (self.center + self.half_width, summary.median),
+        ]
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for BoxElement {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        let [.., left, right] = points[..] else {
+            return Ok(());
+        };
+
+        Boxplot::new_vertical(self.center, &self.quartiles)
+            .style(self.style)
+            .width(right.0.abs_diff(left.0))
+            .whisker_width(0.5)
+            .draw(points[..5].iter().copied(), backend, parent_dim)
+    }
+}
+
+/// Collects samples per category, merging categories given more than once.
+pub(crate) fn group(
+    iter: impl IntoIterator<Item = (impl Into<String>, impl IntoIterator<Item = impl AxisValue>)>,
+) -> Vec<(String, Vec<f64>)> {
+    let mut groups: Vec<(String, Vec<f64>)> = Vec::new();
+
+    for (category, samples) in iter {
+        let category = category.into();
+        let samples = samples.into_iter().map(AxisValue::to_f64);
+
+        match groups.iter_mut().find(|(other, _)| *other == category) {
+            Some((_, group)) => group.extend(samples),
+            None => groups.push((category, samples.collect())),
+        }
+    }
+
+    groups
+}
+
+/// Returns the center and half the width of a shape in the slot of `category`, being the
+/// `position`th of `count` shapes per slot.
+pub(crate) fn placement(
+    slots: &HashMap<&str, usize>,
+    category: &str,
+    (position, count): (usize, usize),
+    width: f64,
+) -> Option<(f64, f64)> {
+    let width = width.clamp(0.0, 1.0) / count.max(1) as f64;
+    let left = *slots.get(category)? as f64 + (1.0 - width * count as f64) / 2.0;
+
+    Some((left + width * (position as f64 + 0.5), width / 2.0))
+}
+
+/// Returns the range of the finite samples of every category.
+pub(crate) fn y_bounds(data: &[(String, Vec<f64>)]) -> Extent {
+    data.iter()
+        .flat_map(|(_, samples)| samples)
+        .filter(|sample| sample.is_finite())
+        .fold(Extent::EMPTY, |extent, &sample| {
+            extent.include(sample..sample)
+        })
+}
+
+/// Samples per category, drawn as boxes from the lower to the upper quartile with a line at
+/// the median, whiskers and outliers.
+///
+/// Boxes of several visible box and violin series are placed side by side within the slot of
+/// their category.
+#[derive(Clone)]
+pub struct BoxplotSeries {
+    pub data: Vec<(String, Vec<f64>)>,
+    pub whiskers: Whiskers,
+    /// The color of the series, or `None` to pick one from the palette of the chart.
+    pub color: Option<Color>,
+    pub label: Option<String>,
+    pub visible: bool,
+    pub axis: Axis,
+    /// The share of a category slot covered by the boxes of all series, between 0 and 1.
+    pub width: f64,
+}
+
+impl BoxplotSeries {
+    pub fn new(
+        iter: impl IntoIterator<Item = (impl Into<String>, impl IntoIterator<Item = impl AxisValue>)>,
+    ) -> Self {
+        Self {
+            data: group(iter),
+            whiskers: Whiskers::default(),
+            color: None,
+            label: None,
+            visible: true,
+            axis: Axis::Primary,
+            width: 0.6,
+        }
+    }
+
+    pub fn whiskers(mut self, whiskers: Whiskers) -> Self {
+        self.whiskers = whiskers;
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the label of the series shown in the legend.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the series is drawn and included in automatic ranges.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the y axis the series is drawn against.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the summary of the samples of `category`, like for a tooltip of the box under
+    /// the cursor.
+    pub fn summary(&self, category: &str) -> Option<BoxSummary> {
+        let (_, samples) = self.data.iter().find(|(other, _)| other == category)?;
+
+        BoxSummary::new(samples, self.whiskers)
+    }
+
+    pub(crate) fn categories(&self) -> impl Iterator<Item = &str> {
+        self.data.iter().map(|(category, _)| category.as_str())
+    }
+
+    pub(crate) fn y_bounds(&self) -> Extent {
+        y_bounds(&self.data)
+    }
+
+    /// Iterates over the box of every category, being the `position`th of `count` shapes per
+    /// slot.
+    pub(crate) fn boxes<'a>(
+        &'a self,
+        slots: &'a HashMap<&str, usize>,
+        group: (usize, usize),
+        style: ShapeStyle,
+    ) -> impl Iterator<Item = BoxElement> + 'a {
+        self.data.iter().filter_map(move |(category, samples)| {
+            let (center, half_width) = placement(slots, category, group, self.width)?;
+            let (summary, quartiles) = BoxSummary::with_quartiles(samples, self.whiskers)?;
+
+            Some(BoxElement {
+                summary,
+                center,
+                half_width,
+                quartiles,
+                style,
+            })
+        })
+    }
+}
+
+pub fn boxplot_series(
+    iter: impl IntoIterator<Item = (impl Into<String>, impl IntoIterator<Item = impl AxisValue>)>,
+) -> BoxplotSeries {
+    BoxplotSeries::new(iter)
+}
@@ -1,24 +1,85 @@
 use std::fmt::{Debug, Formatter};
 
 use plotters::{
-    coord::{types::RangedCoordf32, ReverseCoordTranslate},
+    coord::{CoordTranslate, ReverseCoordTranslate, types::RangedCoordf32},
     prelude::Cartesian2d,
 };
 
+use super::scale::{Scale, ScaledRange};
+
 #[derive(Clone)]
-pub struct Cartesian(Cartesian2d<RangedCoordf32, RangedCoordf32>);
+pub struct Cartesian(
+    Cartesian2d<ScaledRange, ScaledRange>,
+    Option<Cartesian2d<ScaledRange, ScaledRange>>,
+);
 
 impl Cartesian {
+    /// Creates the coordinates of a chart with linear axes and no secondary y axis.
     pub fn new(cartesian: Cartesian2d<RangedCoordf32, RangedCoordf32>) -> Self {
-        Self(cartesian)
+        let (x, y) = (cartesian.get_x_range(), cartesian.get_y_range());
+        // The ends of the ranges keep the direction of the pixel ranges
+        let start = cartesian.translate(&(x.start, y.start));
+        let end = cartesian.translate(&(x.end, y.end));
+        let range = |range: std::ops::Range<f32>| {
+            ScaledRange::new(f64::from(range.start)..f64::from(range.end), Scale::Linear)
+        };
+
+        Self::scaled(
+            Cartesian2d::new(range(x), range(y), (start.0..end.0, start.1..end.1)),
+            None,
+        )
+    }
+
+    /// Creates the coordinates of a chart with scaled axes and an optional secondary y axis.
+    pub(crate) fn scaled(
+        cartesian: Cartesian2d<ScaledRange, ScaledRange>,
+        secondary: Option<Cartesian2d<ScaledRange, ScaledRange>>,
+    ) -> Self {
+        Self(cartesian, secondary)
     }
 
     pub fn get_coords(&self, position: iced::Point) -> Option<iced::Point> {
+        let (x, y) = self.get_values(position)?;
+
+        Some(iced::Point::new(x as f32, y as f32))
+    }
+
+    /// Returns the values under `position` without losing precision.
+    ///
+    /// On a time axis, the value is in seconds since the Unix epoch.
+    pub fn get_values(&self, position: iced::Point) -> Option<(f64, f64)> {
         let plotters_position = (position.x as i32, position.y as i32);
 
-        let cartesian_position = self.0.reverse_translate(plotters_position);
+        self.0.reverse_translate(plotters_position)
+    }
+
+    /// Returns the y value under `position` on the primary and, if any, the secondary y axis.
+    pub fn get_y_values(&self, position: iced::Point) -> Option<(f64, Option<f64>)> {
+        let (_, y) = self.get_values(position)?;
+        let secondary = self.1.as_ref().and_then(|secondary| {
+            let (_, y) = secondary.reverse_translate((position.x as i32, position.y as i32))?;
+
+            Some(y)
+        });
+
+        Some((y, secondary))
+    }
+
+    /// Returns the category under `position` on a categorical x axis.
+    pub fn get_category(&self, position: iced::Point) -> Option<&str> {
+        let (x, _) = self.get_values(position)?;
+
+        self.0.x_spec().category(x)
+    }
+
+    /// Returns the time under `position` on a time x axis.
+    #[cfg(feature = "chrono")]
+    pub fn get_time(&self, position: iced::Point) -> Option<chrono::DateTime<chrono::Utc>> {
+        let (x, _) = self.get_values(position)?;
+        let seconds = x.floor();
+        let nanoseconds = ((x - seconds) * 1e9) as u32;
 
-        cartesian_position.map(iced::Point::from)
+        chrono::DateTime::from_timestamp(seconds as i64, nanoseconds)
     }
 }
 
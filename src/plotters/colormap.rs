@@ -0,0 +1,263 @@
+//! Map scalar values to colors.
+//!
+//! A [`Colormap`] can be sampled continuously with [`Colormap::sample`] or turned into a
+//! discrete palette with [`Colormap::palette`]. It does not depend on a [`Chart`], so it can
+//! also be used from a custom [`Program`].
+//!
+//! [`Chart`]: crate::Chart
+//! [`Program`]: crate::Program
+use super::Color;
+
+use std::fmt;
+
+/// A continuous colormap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Colormap {
+    kind: Kind,
+    reversed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Viridis,
+    Plasma,
+    Inferno,
+    Magma,
+    Cividis,
+    Turbo,
+    RdBu,
+    Coolwarm,
+    Grayscale,
+}
+
+impl Colormap {
+    /// Perceptually uniform, from dark purple to yellow.
+    pub const VIRIDIS: Self = Self::new(Kind::Viridis);
+    /// Perceptually uniform, from dark blue to yellow.
+    pub const PLASMA: Self = Self::new(Kind::Plasma);
+    /// Perceptually uniform, from black over red to light yellow.
+    pub const INFERNO: Self = Self::new(Kind::Inferno);
+    /// Perceptually uniform, from black over pink to light yellow.
+    pub const MAGMA: Self = Self::new(Kind::Magma);
+    /// Perceptually uniform and color vision deficiency friendly, from blue to yellow.
+    pub const CIVIDIS: Self = Self::new(Kind::Cividis);
+    /// A rainbow colormap with smooth lightness, from dark blue to dark red.
+    pub const TURBO: Self = Self::new(Kind::Turbo);
+    /// Diverging, from red over white to blue.
+    pub const RD_BU: Self = Self::new(Kind::RdBu);
+    /// Diverging, from blue over light gray to red.
+    pub const COOLWARM: Self = Self::new(Kind::Coolwarm);
+    /// From black to white.
+    pub const GRAYSCALE: Self = Self::new(Kind::Grayscale);
+
+    /// All colormaps that are not reversed.
+    pub const ALL: [Self; 9] = [
+        Self::VIRIDIS,
+        Self::PLASMA,
+        Self::INFERNO,
+        Self::MAGMA,
+        Self::CIVIDIS,
+        Self::TURBO,
+        Self::RD_BU,
+        Self::COOLWARM,
+        Self::GRAYSCALE,
+    ];
+
+    const fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            reversed: false,
+        }
+    }
+
+    /// Returns the colormap running in the opposite direction.
+    pub const fn reversed(self) -> Self {
+        Self {
+            reversed: !self.reversed,
+            ..self
+        }
+    }
+
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Returns whether the colormap is meant for data diverging from a center value.
+    pub fn is_diverging(&self) -> bool {
+        matches!(self.kind, Kind::RdBu | Kind::Coolwarm)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self.kind {
+            Kind::Viridis => "viridis",
+            Kind::Plasma => "plasma",
+            Kind::Inferno => "inferno",
+            Kind::Magma => "magma",
+            Kind::Cividis => "cividis",
+            Kind::Turbo => "turbo",
+            Kind::RdBu => "RdBu",
+            Kind::Coolwarm => "coolwarm",
+            Kind::Grayscale => "grayscale",
+        }
+    }
+
+    /// Samples the colormap at `t`, which is clamped to `0.0..=1.0`.
+    pub fn sample(&self, t: f32) -> Color {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let t = if self.reversed { 1.0 - t } else { t };
+
+        let rgb = match self.kind {
+            Kind::Viridis => polynomial(&VIRIDIS, t),
+            Kind::Plasma => polynomial(&PLASMA, t),
+            Kind::Inferno => polynomial(&INFERNO, t),
+            Kind::Magma => polynomial(&MAGMA, t),
+            Kind::Cividis => interpolate(&CIVIDIS, t),
+            Kind::Turbo => polynomial(&TURBO, t),
+            Kind::RdBu => interpolate(&RD_BU, t),
+            Kind::Coolwarm => interpolate(&COOLWARM, t),
+            Kind::Grayscale => [t, t, t],
+        };
+
+        Color(iced::Color::from_rgb(rgb[0], rgb[1], rgb[2]))
+    }
+
+    /// Samples `count` evenly spaced colors, including both ends of the colormap.
+    pub fn palette(&self, count: usize) -> Vec<Color> {
+        match count {
+            0 => Vec::new(),
+            1 => vec![self.sample(0.5)],
+            _ => (0..count)
+                .map(|i| self.sample(i as f32 / (count - 1) as f32))
+                .collect(),
+        }
+    }
+}
+
+impl Default for Colormap {
+    fn default() -> Self {
+        Self::VIRIDIS
+    }
+}
+
+impl fmt::Display for Colormap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.reversed {
+            write!(f, "{}_r", self.name())
+        } else {
+            f.write_str(self.name())
+        }
+    }
+}
+
+/// Polynomial fits of the matplotlib colormaps, coefficients in ascending order.
+const VIRIDIS: [[f32; 3]; 7] = [
+    [0.277_727_33, 0.005_407_344_5, 0.334_099_8],
+    [0.105_093_04, 1.404_613_5, 1.384_590_2],
+    [-0.330_861_83, 0.214_847_56, 0.095_095_16],
+    [-4.634_230_6, -5.799_101, -19.332_441],
+    [6.228_27, 14.179_933, 56.690_55],
+    [4.776_385, -13.745_146, -65.353_03],
+    [-5.435_456, 4.645_852_6, 26.312_435],
+];
+
+const PLASMA: [[f32; 3]; 7] = [
+    [0.058_732_344, 0.023_336_709, 0.543_340_2],
+    [2.176_514_6, 0.238_383_42, 0.753_960_45],
+    [-2.689_460_5, -7.455_851, 3.110_8],
+    [6.130_348, 42.346_188, -28.518_854],
+    [-11.107_436, -82.666_31, 60.139_848],
+    [10.023_066, 71.413_62, -54.072_186],
+    [-3.658_714, -22.931_534, 18.191_908],
+];
+
+const INFERNO: [[f32; 3]; 7] = [
+    [0.000_218_940_37, 0.001_651_004_6, -0.019_480_899],
+    [0.106_513_42, 0.563_956_4, 3.932_712_4],
+    [11.602_493, -3.972_854, -15.942_394],
+    [-41.703_995, 17.436_4, 44.354_145],
+    [77.162_94, -33.402_36, -81.807_31],
+    [-71.319_43, 32.626_064, 73.209_52],
+    [25.131_126, -12.242_669, -23.070_325],
+];
+
+const MAGMA: [[f32; 3]; 7] = [
+    [-0.002_136_485, -0.000_749_655_05, -0.005_386_128],
+    [0.251_660_54, 0.677_523_2, 2.494_026_6],
+    [8.353_717, -3.577_719_5, 0.314_467_9],
+    [-27.668_733, 14.264_731, -13.649_213],
+    [52.176_14, -27.943_607, 12.944_169],
+    [-50.768_524, 29.046_583, 4.234_153],
+    [18.655_705, -11.489_774, -5.601_961_5],
+];
+
+/// Polynomial approximation of Google's turbo colormap.
+const TURBO: [[f32; 3]; 6] = [
+    [0.135_721_38, 0.091_402_61, 0.106_673_3],
+    [4.615_392_6, 2.194_188_4, 12.641_946],
+    [-42.660_324, 4.842_966_6, -60.582_05],
+    [132.131_08, -14.185_033, 110.362_77],
+    [-152.942_4, 4.277_298_5, -89.903_11],
+    [59.286_38, 2.829_566, 27.348_25],
+];
+
+const CIVIDIS: [[u8; 3]; 5] = [
+    [0, 32, 77],
+    [65, 77, 107],
+    [124, 123, 120],
+    [188, 175, 111],
+    [255, 233, 69],
+];
+
+const RD_BU: [[u8; 3]; 11] = [
+    [103, 0, 31],
+    [178, 24, 43],
+    [214, 96, 77],
+    [244, 165, 130],
+    [253, 219, 199],
+    [247, 247, 247],
+    [209, 229, 240],
+    [146, 197, 222],
+    [67, 147, 195],
+    [33, 102, 172],
+    [5, 48, 97],
+];
+
+const COOLWARM: [[u8; 3]; 9] = [
+    [59, 76, 192],
+    [98, 130, 234],
+    [141, 176, 254],
+    [184, 208, 249],
+    [221, 221, 221],
+    [245, 196, 173],
+    [244, 154, 123],
+    [222, 96, 77],
+    [180, 4, 38],
+];
+
+fn polynomial(coefficients: &[[f32; 3]], t: f32) -> [f32; 3] {
+    let mut rgb = [0.0; 3];
+
+    for coefficient in coefficients.iter().rev() {
+        for (channel, c) in rgb.iter_mut().zip(coefficient) {
+            *channel = *channel * t + c;
+        }
+    }
+
+    rgb.map(|channel| channel.clamp(0.0, 1.0))
+}
+
+/// Linearly interpolates between evenly spaced colors.
+fn interpolate(colors: &[[u8; 3]], t: f32) -> [f32; 3] {
+    let position = t * (colors.len() - 1) as f32;
+    let index = (position.floor() as usize).min(colors.len() - 2);
+    let fraction = position - index as f32;
+
+    let (from, to) = (colors[index], colors[index + 1]);
+
+    [0, 1, 2].map(|channel| {
+        let from = f32::from(from[channel]);
+        let to = f32::from(to[channel]);
+
+        (from + (to - from) * fraction) / 255.0
+    })
+}
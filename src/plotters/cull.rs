@@ -0,0 +1,79 @@
+use std::ops::Range;
+
+/// Returns the part of `data` drawn within `x_range`, keeping one point on each side of it so a
+/// line reaches the border.
+///
+/// Data known to be `sorted` by x is searched in logarithmic time, other data is scanned.
+pub(crate) fn visible(data: &[(f64, f64)], x_range: Range<f64>, sorted: bool) -> &[(f64, f64)] {
+    &data[visible_indices(data, x_range, sorted)]
+}
+
+/// Returns the indices of the [`visible`] part of `data`.
+pub(crate) fn visible_indices(
+    data: &[(f64, f64)],
+    x_range: Range<f64>,
+    sorted: bool,
+) -> Range<usize> {
+    let (start, end) = (
+        x_range.start.min(x_range.end),
+        x_range.start.max(x_range.end),
+    );
+
+    if data.len() < 2 {
+        let inside = data.iter().all(|point| (start..=end).contains(&point.0));
+
+        return if inside { 0..data.len() } else { 0..0 };
+    }
+
+    if sorted {
+        let first = data.partition_point(|point| point.0 < start);
+        let last = data.partition_point(|point| point.0 <= end);
+
+        return if last == 0 || first == data.len() {
+            0..0
+        } else {
+            first.saturating_sub(1)..(last + 1).min(data.len())
+        };
+    }
+
+    // The line between two points is visible if their x span overlaps the range
+    let overlaps =
+        |pair: &[(f64, f64)]| pair[0].0.min(pair[1].0) <= end && pair[0].0.max(pair[1].0) >= start;
+
+    match (
+        data.windows(2).position(overlaps),
+        data.windows(2).rposition(overlaps),
+    ) {
+        (Some(first), Some(last)) => first..last + 2,
+        _ => 0..0,
+    }
+}
+
+/// Returns the parts of a line in any order of x which are drawn within `x_range`, breaking it
+/// with a NaN point wherever it leaves the range.
+pub(crate) fn crop(data: &[(f64, f64)], x_range: Range<f64>) -> Vec<(f64, f64)> {
+    let (start, end) = (
+        x_range.start.min(x_range.end),
+        x_range.start.max(x_range.end),
+    );
+    let overlaps = |a: (f64, f64), b: (f64, f64)| a.0.min(b.0) <= end && a.0.max(b.0) >= start;
+
+    let mut cropped = Vec::new();
+
+    for (i, &point) in data.iter().enumerate() {
+        let drawn = (start..=end).contains(&point.0)
+            || i > 0 && overlaps(data[i - 1], point)
+            || data.get(i + 1).is_some_and(|&next| overlaps(point, next));
+
+        if drawn {
+            cropped.push(point);
+        } else if cropped
+            .last()
+            .is_some_and(|last: &(f64, f64)| !last.0.is_nan())
+        {
+            cropped.push((f64::NAN, f64::NAN));
+        }
+    }
+
+    cropped
+}
@@ -0,0 +1,144 @@
+use super::{AxisValue, DataSource, Extent, cull};
+
+use std::borrow::Cow;
+use std::ops::{Deref, Range};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+/// Points shared between an application and the series drawing them.
+///
+/// Cloning is cheap, so a series can be rebuilt in every `view` without copying the points.
+/// Every change gives the data a new version, which tells a cached [`Chart`] to redraw.
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone, Default)]
+pub struct Data {
+    points: Arc<Vec<(f64, f64)>>,
+    version: u64,
+    bounds: Arc<OnceLock<(Extent, Extent)>>,
+    /// Whether the points are in order of x, which lets ranges be searched.
+    sorted: Arc<OnceLock<bool>>,
+}
+
+impl Data {
+    pub fn new(iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>) -> Self {
+        Self {
+            points: Arc::new(collect(iter)),
+            version: next_version(),
+            bounds: Arc::default(),
+            sorted: Arc::default(),
+        }
+    }
+
+    /// Collects the points of a series built from an iterator, which are not tracked.
+    pub(crate) fn untracked(
+        iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>,
+    ) -> Self {
+        Self {
+            points: Arc::new(collect(iter)),
+            version: 0,
+            bounds: Arc::default(),
+            sorted: Arc::default(),
+        }
+    }
+
+    pub fn push(&mut self, (x, y): (impl AxisValue, impl AxisValue)) {
+        self.update(|points| points.push((x.to_f64(), y.to_f64())));
+    }
+
+    pub fn extend(&mut self, iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>) {
+        self.update(|points| points.extend(collect(iter)));
+    }
+
+    pub fn clear(&mut self) {
+        self.update(Vec::clear);
+    }
+
+    /// Changes the points in place.
+    ///
+    /// They are only copied if a series drawing them is still alive.
+    pub fn update<T>(&mut self, f: impl FnOnce(&mut Vec<(f64, f64)>) -> T) -> T {
+        self.version = next_version();
+        reset(&mut self.bounds);
+        reset(&mut self.sorted);
+
+        f(Arc::make_mut(&mut self.points))
+    }
+
+    /// Returns whether the points are in order of x, checked once per version.
+    pub(crate) fn is_sorted(&self) -> bool {
+        *self
+            .sorted
+            .get_or_init(|| self.points.is_sorted_by(|a, b| a.0 <= b.0))
+    }
+}
+
+impl DataSource for Data {
+    /// Returns the extent of the points, computed once per version.
+    fn bounds(&self) -> (Extent, Extent) {
+        self.bounds
+            .get_or_init(|| {
+                self.points
+                    .iter()
+                    .fold((Extent::EMPTY, Extent::EMPTY), |(x, y), &(px, py)| {
+                        (x.include(px..px), y.include(py..py))
+                    })
+            })
+            .clone()
+    }
+
+    fn points(&self, x_range: Range<f64>) -> Cow<'_, [(f64, f64)]> {
+        Cow::Borrowed(cull::visible(&self.points, x_range, self.is_sorted()))
+    }
+
+    fn indexed_points(&self, x_range: Range<f64>) -> (usize, Cow<'_, [(f64, f64)]>) {
+        let indices = cull::visible_indices(&self.points, x_range, self.is_sorted());
+
+        (indices.start, Cow::Borrowed(&self.points[indices]))
+    }
+
+    fn version(&self) -> u64 {
+        self.version
+    }
+}
+
+impl Deref for Data {
+    type Target = [(f64, f64)];
+
+    fn deref(&self) -> &Self::Target {
+        &self.points
+    }
+}
+
+impl From<Vec<(f64, f64)>> for Data {
+    fn from(points: Vec<(f64, f64)>) -> Self {
+        Self {
+            points: Arc::new(points),
+            version: next_version(),
+            bounds: Arc::default(),
+            sorted: Arc::default(),
+        }
+    }
+}
+
+fn collect(iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>) -> Vec<(f64, f64)> {
+    iter.into_iter()
+        .map(|(x, y)| (x.to_f64(), y.to_f64()))
+        .collect()
+}
+
+/// Forgets a cached value, without touching the cache of clones drawing the old points.
+fn reset<T>(cache: &mut Arc<OnceLock<T>>) {
+    match Arc::get_mut(cache) {
+        Some(cache) => {
+            cache.take();
+        }
+        None => *cache = Arc::default(),
+    }
+}
+
+fn next_version() -> u64 {
+    static VERSION: AtomicU64 = AtomicU64::new(1);
+
+    VERSION.fetch_add(1, Ordering::Relaxed)
+}
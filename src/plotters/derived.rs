@@ -0,0 +1,610 @@
+use super::scale::Extent;
+use super::{Axis, Color, Data, DataSource};
+
+/// How a [`DerivedSeries`] is computed from the points of its parent series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Derivation {
+    /// The mean of every point and the `n - 1` points before it.
+    MovingAverage(usize),
+    /// The exponentially weighted mean of the points up to every point, where `alpha` in
+    /// `(0, 1]` is the weight of the newest one.
+    ExponentialMovingAverage(f64),
+    Regression(Regression),
+    /// The running total of the y values.
+    CumulativeSum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Regression {
+    /// The least squares line.
+    Linear,
+    /// The least squares polynomial of the given degree.
+    Polynomial(usize),
+    /// A locally weighted line at every x, fitted to the given share of the closest points,
+    /// like `0.3`.
+    Loess(f64),
+}
+
+/// A polynomial fitted by a regression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fit {
+    /// The coefficients, starting with the constant term.
+    pub coefficients: Vec<f64>,
+    /// The share of the variance of y explained by the fit.
+    pub r_squared: f64,
+}
+
+impl Fit {
+    pub fn eval(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |value, coefficient| value * x + coefficient)
+    }
+}
+
+/// A line computed from the points of another series, like a moving average or a regression.
+///
+/// It is created with [`LineSeries::derive`] or [`PointSeries::derive`] and pushed to the
+/// chart next to its parent, with its own style and legend entry.
+///
+/// [`LineSeries::derive`]: crate::LineSeries::derive
+/// [`PointSeries::derive`]: crate::PointSeries::derive
+#[derive(Clone)]
+pub struct DerivedSeries {
+    /// The derived points, drawn as a line which is broken at NaN values.
+    pub data: Data,
+    /// The polynomial of a linear or polynomial regression.
+    pub fit: Option<Fit>,
+    /// The level of the confidence band drawn around a regression, like `0.95`.
+    pub confidence: Option<f64>,
+    /// The color of the series, or `None` to pick one from the palette of the chart.
+    pub color: Option<Color>,
+    pub label: Option<String>,
+    pub visible: bool,
+    pub axis: Axis,
+    /// The standard error of every point of a regression.
+    errors: Vec<f64>,
+    /// The degrees of freedom of the errors.
+    degrees_of_freedom: f64,
+}
+
+impl DerivedSeries {
+    /// The number of points a regression is drawn with.
+    const SAMPLES: usize = 200;
+
+    pub fn new(source: &dyn DataSource, derivation: Derivation) -> Self {
+        let points = source.points(f64::NEG_INFINITY..f64::INFINITY);
+        let mut series = Self {
+            data: Data::default(),
+            fit: None,
+            confidence: None,
+            color: None,
+            label: None,
+            visible: true,
+            axis: Axis::Primary,
+            errors: Vec::new(),
+            degrees_of_freedom: 0.0,
+        };
+
+        match derivation {
+            Derivation::MovingAverage(n) => {
+                series.data = Data::untracked(moving_average(&points, n.max(1)));
+            }
+            Derivation::ExponentialMovingAverage(alpha) => {
+                let alpha = alpha.clamp(f64::EPSILON, 1.0);
+                series.data = Data::untracked(exponential_moving_average(&points, alpha));
+            }
+            Derivation::CumulativeSum => series.data = Data::untracked(cumulative_sum(&points)),
+            Derivation::Regression(regression) => {
+                let finite: Vec<(f64, f64)> = points
+                    .iter()
+                    .copied()
+                    .filter(|(x, y)| x.is_finite() && y.is_finite())
+                    .collect();
+
+                let regression = match regression {
+                    Regression::Linear => polynomial(&finite, 1, Self::SAMPLES),
+                    Regression::Polynomial(degree) => polynomial(&finite, degree, Self::SAMPLES),
+                    Regression::Loess(span) => loess(&finite, span, Self::SAMPLES),
+                };
+
+                if let Some(regression) = regression {
+                    series.data = Data::untracked(regression.points);
+                    series.fit = regression.fit;
+                    series.errors = regression.errors;
+                    series.degrees_of_freedom = regression.degrees_of_freedom;
+                }
+            }
+        }
+
+        series
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the label of the series shown in the legend.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the series is drawn and included in automatic ranges.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the y axis the series is drawn against.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Draws the band in which the regression lies with the probability `level`, like `0.95`.
+    ///
+    /// The band of a LOESS regression is approximate.
+    pub fn confidence(mut self, level: f64) -> Self {
+        self.confidence = Some(level);
+        self
+    }
+
+    /// Returns the x, lower and upper y value of the confidence band at every point.
+    ///
+    /// It is empty without a [`confidence`](Self::confidence) level or a regression.
+    pub fn band(&self) -> Vec<(f64, f64, f64)> {
+        let Some(level) = self.confidence else {
+            return Vec::new();
+        };
+        let t = t_quantile(
+            (1.0 + level.clamp(0.0, 1.0 - f64::EPSILON)) / 2.0,
+            self.degrees_of_freedom,
+        );
+
+        self.data
+            .iter()
+            .zip(&self.errors)
+            .map(|(&(x, y), error)| (x, y - t * error, y + t * error))
+            .collect()
+    }
+
+    pub(crate) fn x_bounds(&self) -> Extent {
+        self.data.bounds().0
+    }
+
+    /// Returns the y range covered by the series, including its confidence band.
+    pub(crate) fn y_bounds(&self) -> Extent {
+        self.band()
+            .into_iter()
+            .fold(self.data.bounds().1, |extent, (_, lower, upper)| {
+                extent.include(lower..upper)
+            })
+    }
+}
+
+/// The points of a regression and the standard errors of their y values.
+struct Curve {
+    points: Vec<(f64, f64)>,
+    fit: Option<Fit>,
+    errors: Vec<f64>,
+    degrees_of_freedom: f64,
+}
+
+/// Returns the mean of every window of `n` points, restarting after NaN values.
+fn moving_average(points: &[(f64, f64)], n: usize) -> Vec<(f64, f64)> {
+    let mut averages = Vec::with_capacity(points.len());
+    let mut run = 0;
+    let mut sum = 0.0;
+
+    for (i, &(x, y)) in points.iter().enumerate() {
+        if !y.is_finite() {
+            averages.push((x, f64::NAN));
+            run = 0;
+            sum = 0.0;
+            continue;
+        }
+
+        sum += y;
+        run += 1;
+
+        if run > n {
+            sum -= points[i - n].1;
+        }
+
+        if run >= n {
+            averages.push((x, sum / n as f64));
+        }
+    }
+
+    averages
+}
+
+fn exponential_moving_average(points: &[(f64, f64)], alpha: f64) -> Vec<(f64, f64)> {
+    let mut average = None;
+
+    points
+        .iter()
+        .map(|&(x, y)| {
+            if !y.is_finite() {
+                average = None;
+                return (x, f64::NAN);
+            }
+
+            let value = average.map_or(y, |average| alpha * y + (1.0 - alpha) * average);
+            average = Some(value);
+
+            (x, value)
+        })
+        .collect()
+}
+
+/// Returns the running total of the y values, skipping NaN values.
+fn cumulative_sum(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut sum = 0.0;
+
+    points
+        .iter()
+        .filter(|(_, y)| y.is_finite())
+        .map(|&(x, y)| {
+            sum += y;
+            (x, sum)
+        })
+        .collect()
+}
+
+/// Returns `samples` evenly spaced x values over the x extent of `points`.
+fn grid(points: &[(f64, f64)], samples: usize) -> Vec<f64> {
+    let (start, end) = points.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(start, end), &(x, _)| (start.min(x), end.max(x)),
+    );
+    let step = (end - start) / (samples - 1) as f64;
+
+    (0..samples).map(|i| start + step * i as f64).collect()
+}
+
+/// Fits a least squares polynomial of `degree` to `points`.
+///
+/// The x values are centered and scaled for the fit, so high degrees stay well conditioned.
+fn polynomial(points: &[(f64, f64)], degree: usize, samples: usize) -> Option<Curve> {
+    let terms = degree + 1;
+    let n = points.len();
+
+    if n < terms {
+        return None;
+    }
+
+    let mean = points.iter().map(|(x, _)| x).sum::<f64>() / n as f64;
+    let scale = points
+        .iter()
+        .map(|(x, _)| (x - mean).abs())
+        .fold(0.0, f64::max);
+
+    if scale == 0.0 && degree > 0 {
+        return None;
+    }
+
+    let scale = if scale == 0.0 { 1.0 } else { scale };
+    let powers = |x: f64| {
+        let u = (x - mean) / scale;
+
+        std::iter::successors(Some(1.0), move |power| Some(power * u))
+            .take(terms)
+            .collect::<Vec<f64>>()
+    };
+
+    // The normal equations
+    let mut gram = vec![vec![0.0; terms]; terms];
+    let mut moments = vec![0.0; terms];
+
+    for &(x, y) in points {
+        let powers = powers(x);
+
+        for (i, a) in powers.iter().enumerate() {
+            moments[i] += a * y;
+
+            for (j, b) in powers.iter().enumerate() {
+                gram[i][j] += a * b;
+            }
+        }
+    }
+
+    let inverse = invert(gram)?;
+    let coefficients: Vec<f64> = inverse
+        .iter()
+        .map(|row| row.iter().zip(&moments).map(|(a, b)| a * b).sum())
+        .collect();
+    let eval =
+        |powers: &[f64]| -> f64 { powers.iter().zip(&coefficients).map(|(a, b)| a * b).sum() };
+
+    let y_mean = points.iter().map(|(_, y)| y).sum::<f64>() / n as f64;
+    let (residual, total) = points
+        .iter()
+        .fold((0.0, 0.0), |(residual, total), &(x, y)| {
+            (
+                residual + (y - eval(&powers(x))).powi(2),
+                total + (y - y_mean).powi(2),
+            )
+        });
+
+    let degrees_of_freedom = (n - terms) as f64;
+    let variance = residual / degrees_of_freedom;
+    let grid = grid(points, samples);
+    let curve: Vec<(f64, f64)> = grid.iter().map(|&x| (x, eval(&powers(x)))).collect();
+    let errors = if degrees_of_freedom > 0.0 {
+        grid.iter()
+            .map(|&x| {
+                let powers = powers(x);
+                let spread: f64 = inverse
+                    .iter()
+                    .zip(&powers)
+                    .map(|(row, a)| a * row.iter().zip(&powers).map(|(b, c)| b * c).sum::<f64>())
+                    .sum();
+
+                (variance * spread).max(0.0).sqrt()
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    Some(Curve {
+        points: curve,
+        fit: Some(Fit {
+            coefficients: unscale(&coefficients, mean, scale),
+            r_squared: if total > 0.0 {
+                1.0 - residual / total
+            } else {
+                1.0
+            },
+        }),
+        errors,
+        degrees_of_freedom,
+    })
+}
+
+/// Turns the coefficients of a polynomial in `(x - mean) / scale` into those of one in `x`.
+fn unscale(coefficients: &[f64], mean: f64, scale: f64) -> Vec<f64> {
+    let mut unscaled = vec![0.0; coefficients.len()];
+
+    for (k, coefficient) in coefficients.iter().enumerate() {
+        // The binomial expansion of (x - mean)^k
+        let mut binomial = 1.0;
+
+        for (j, unscaled) in unscaled.iter_mut().enumerate().take(k + 1) {
+            *unscaled +=
+                coefficient / scale.powi(k as i32) * binomial * (-mean).powi((k - j) as i32);
+            binomial = binomial * (k - j) as f64 / (j + 1) as f64;
+        }
+    }
+
+    unscaled
+}
+
+/// Inverts a symmetric matrix with Gauss-Jordan elimination, or returns `None` if it is
+/// singular.
+fn invert(mut matrix: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut inverse: Vec<Vec<f64>> = (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for column in 0..n {
+        let pivot = (column..n).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .total_cmp(&matrix[*b][column].abs())
+        })?;
+
+        if matrix[pivot][column].abs() < 1e-12 {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+        inverse.swap(column, pivot);
+
+        let divisor = matrix[column][column];
+
+        for j in 0..n {
+            matrix[column][j] /= divisor;
+            inverse[column][j] /= divisor;
+        }
+
+        for row in 0..n {
+            let factor = matrix[row][column];
+
+            if row == column || factor == 0.0 {
+                continue;
+            }
+
+            for j in 0..n {
+                matrix[row][j] -= factor * matrix[column][j];
+                inverse[row][j] -= factor * inverse[column][j];
+            }
+        }
+    }
+
+    Some(inverse)
+}
+
+/// Fits a line at every x of a grid to the `span` share of closest points, weighted by their
+/// tricube distance.
+fn loess(points: &[(f64, f64)], span: f64, samples: usize) -> Option<Curve> {
+    let n = points.len();
+
+    if n < 3 {
+        return None;
+    }
+
+    let neighbours = ((span * n as f64).ceil() as usize).clamp(3, n);
+    let grid = grid(points, samples);
+    let mut distances = vec![0.0; n];
+    let mut curve = Vec::with_capacity(samples);
+    // The squared length of the weights of the y values in every fitted value
+    let mut leverages = Vec::with_capacity(samples);
+
+    for &x0 in &grid {
+        for (distance, (x, _)) in distances.iter_mut().zip(points) {
+            *distance = (x - x0).abs();
+        }
+
+        let radius = *distances
+            .select_nth_unstable_by(neighbours - 1, f64::total_cmp)
+            .1;
+        let weight = |x: f64| {
+            let distance = (x - x0).abs();
+
+            if radius == 0.0 && distance == 0.0 {
+                1.0
+            } else if distance < radius {
+                (1.0 - (distance / radius).powi(3)).powi(3)
+            } else {
+                0.0
+            }
+        };
+
+        let (sum, x_sum, y_sum) = points.iter().fold((0.0, 0.0, 0.0), |(s, sx, sy), &(x, y)| {
+            let w = weight(x);
+            (s + w, sx + w * x, sy + w * y)
+        });
+        let (x_mean, y_mean) = (x_sum / sum, y_sum / sum);
+        let (xx, xy) = points.iter().fold((0.0, 0.0), |(xx, xy), &(x, y)| {
+            let w = weight(x);
+            (
+                xx + w * (x - x_mean).powi(2),
+                xy + w * (x - x_mean) * (y - y_mean),
+            )
+        });
+        let slope = if xx > 0.0 { xy / xx } else { 0.0 };
+        let leverage: f64 = points
+            .iter()
+            .map(|&(x, _)| {
+                let w = weight(x);
+                let tilt = if xx > 0.0 {
+                    (x0 - x_mean) * w * (x - x_mean) / xx
+                } else {
+                    0.0
+                };
+
+                (w / sum + tilt).powi(2)
+            })
+            .sum();
+
+        curve.push((x0, y_mean + slope * (x0 - x_mean)));
+        leverages.push(leverage);
+    }
+
+    // The residuals, against the fit interpolated between the grid points
+    let step = grid[1] - grid[0];
+    let residual: f64 = points
+        .iter()
+        .map(|&(x, y)| {
+            let position = if step > 0.0 {
+                (x - grid[0]) / step
+            } else {
+                0.0
+            };
+            let i = (position.floor() as usize).min(samples - 2);
+            let t = position - i as f64;
+            let fitted = curve[i].1 + (curve[i + 1].1 - curve[i].1) * t;
+
+            (y - fitted).powi(2)
+        })
+        .sum();
+    let degrees_of_freedom = (n - 2) as f64;
+    let variance = residual / degrees_of_freedom;
+
+    Some(Curve {
+        points: curve,
+        fit: None,
+        errors: leverages
+            .into_iter()
+            .map(|leverage| (variance * leverage).sqrt())
+            .collect(),
+        degrees_of_freedom,
+    })
+}
+
+/// Returns the quantile `p` of Student's t distribution, exactly for up to two degrees of
+/// freedom and with a Cornish-Fisher expansion of the normal quantile above.
+fn t_quantile(p: f64, degrees_of_freedom: f64) -> f64 {
+    if degrees_of_freedom < 1.0 {
+        return f64::NAN;
+    } else if degrees_of_freedom < 2.0 {
+        return (std::f64::consts::PI * (p - 0.5)).tan();
+    } else if degrees_of_freedom < 3.0 {
+        return (2.0 * p - 1.0) / (2.0 * p * (1.0 - p)).sqrt();
+    }
+
+    let z = normal_quantile(p);
+    let terms = [
+        (z.powi(3) + z) / 4.0,
+        (5.0 * z.powi(5) + 16.0 * z.powi(3) + 3.0 * z) / 96.0,
+        (3.0 * z.powi(7) + 19.0 * z.powi(5) + 17.0 * z.powi(3) - 15.0 * z) / 384.0,
+        (79.0 * z.powi(9) + 776.0 * z.powi(7) + 1482.0 * z.powi(5)
+            - 1920.0 * z.powi(3)
+            - 945.0 * z)
+            / 92160.0,
+    ];
+
+    terms.iter().zip(1..).fold(z, |t, (term, power)| {
+        t + term / degrees_of_freedom.powi(power)
+    })
+}
+
+/// Returns the quantile `p` of the standard normal distribution, with the rational
+/// approximation of Peter Acklam.
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const LOW: f64 = 0.02425;
+
+    let polynomial = |coefficients: &[f64], x: f64| coefficients.iter().fold(0.0, |v, c| v * x + c);
+
+    if p <= 0.0 {
+        f64::NEG_INFINITY
+    } else if p >= 1.0 {
+        f64::INFINITY
+    } else if p < LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+
+        polynomial(&C, q) / (polynomial(&D, q) * q + 1.0)
+    } else if p > 1.0 - LOW {
+        -normal_quantile(1.0 - p)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+
+        polynomial(&A, r) * q / (polynomial(&B, r) * r + 1.0)
+    }
+}
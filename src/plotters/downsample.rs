@@ -0,0 +1,206 @@
+//! Level-of-detail reduction for line series with more points than pixels.
+//!
+//! The algorithms expect the points to be sorted by x.
+use std::ops::Range;
+
+/// How a [`LineSeries`](crate::LineSeries) with more points than the chart is wide is reduced
+/// before drawing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Downsample {
+    /// Every point is drawn.
+    Disabled,
+    /// Largest-Triangle-Three-Buckets, keeping two points per pixel column.
+    Lttb,
+    /// The first, lowest, highest and last point of every pixel column, which draws the same
+    /// line as all points.
+    #[default]
+    MinMax,
+}
+
+impl Downsample {
+    /// Reduces a line drawn over `columns` pixels spanning `x_range`, keeping its breaks at values
+    /// which are not finite, or returns `None` if it is small enough.
+    ///
+    /// The parts between breaks share the columns by their x span, so together they stay within
+    /// the budget of the whole line.
+    pub(crate) fn apply(
+        &self,
+        data: &[(f64, f64)],
+        x_range: Range<f64>,
+        columns: usize,
+    ) -> Option<Vec<(f64, f64)>> {
+        let threshold = match self {
+            Downsample::Disabled => return None,
+            Downsample::Lttb => columns * 2,
+            Downsample::MinMax => columns * 4,
+        };
+
+        if data.len() <= threshold {
+            return None;
+        }
+
+        let x_range = visible_span(data, x_range);
+        let mut reduced = Vec::new();
+
+        for (index, run) in data
+            .split(|point| !point.0.is_finite() || !point.1.is_finite())
+            .enumerate()
+        {
+            if index > 0 {
+                reduced.push((f64::NAN, f64::NAN));
+            }
+
+            match self {
+                Downsample::Lttb => {
+                    reduced.extend(lttb(run, share(run, &x_range, threshold).max(3)))
+                }
+                Downsample::Disabled | Downsample::MinMax => {
+                    reduced.extend(min_max(run, x_range.clone(), columns))
+                }
+            }
+        }
+
+        Some(reduced)
+    }
+}
+
+/// Reduces `data` to `threshold` points with the Largest-Triangle-Three-Buckets algorithm.
+///
+/// The first and last point are always kept. Between them, each bucket keeps the point forming
+/// the largest triangle with the previously kept point and the average of the next bucket.
+pub fn lttb(data: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
+    if threshold >= data.len() || threshold < 3 {
+        return data.to_vec();
+    }
+
+    let every = (data.len() - 2) as f64 / (threshold - 2) as f64;
+    let mut sampled = Vec::with_capacity(threshold);
+    let mut a = 0;
+
+    sampled.push(data[0]);
+
+    for i in 0..threshold - 2 {
+        let next_start = ((i + 1) as f64 * every) as usize + 1;
+        let next_end = (((i + 2) as f64 * every) as usize + 1).min(data.len());
+        let next = &data[next_start..next_end];
+        let average = next
+            .iter()
+            .fold((0.0, 0.0), |(x, y), point| (x + point.0, y + point.1));
+        let average = (average.0 / next.len() as f64, average.1 / next.len() as f64);
+
+        let start = (i as f64 * every) as usize + 1;
+        let end = next_start;
+        let (ax, ay) = data[a];
+
+        let (index, _) = data[start..end]
+            .iter()
+            .enumerate()
+            .map(|(offset, &(x, y))| {
+                let area = ((ax - average.0) * (y - ay) - (ax - x) * (average.1 - ay)).abs();
+
+                (start + offset, area)
+            })
+            .fold((start, -1.0), |max, (index, area)| {
+                if area > max.1 { (index, area) } else { max }
+            });
+
+        sampled.push(data[index]);
+        a = index;
+    }
+
+    sampled.push(data[data.len() - 1]);
+    sampled
+}
+
+/// Splits `x_range` into `buckets` columns and keeps the first, lowest, highest and last point of
+/// each, in their original order.
+///
+/// Points outside of `x_range` are put into the first or last column.
+pub fn min_max(data: &[(f64, f64)], x_range: Range<f64>, buckets: usize) -> Vec<(f64, f64)> {
+    let span = x_range.end - x_range.start;
+
+    if buckets == 0 || span <= 0.0 || !span.is_finite() {
+        return data.to_vec();
+    }
+
+    let bucket = |x: f64| {
+        let bucket = ((x - x_range.start) / span * buckets as f64).floor();
+
+        bucket.clamp(0.0, (buckets - 1) as f64) as usize
+    };
+
+    let mut sampled = Vec::with_capacity(buckets * 4);
+    let mut start = 0;
+
+    while start < data.len() {
+        let current = bucket(data[start].0);
+        let end = data[start..]
+            .iter()
+            .position(|point| bucket(point.0) != current)
+            .map_or(data.len(), |length| start + length);
+
+        let (mut low, mut high) = (start, start);
+
+        for index in start..end {
+            if data[index].1 < data[low].1 {
+                low = index;
+            }
+
+            if data[index].1 > data[high].1 {
+                high = index;
+            }
+        }
+
+        let mut kept = [start, low, high, end - 1];
+        kept.sort_unstable();
+
+        let mut previous = None;
+
+        for index in kept {
+            if previous != Some(index) {
+                sampled.push(data[index]);
+                previous = Some(index);
+            }
+        }
+
+        start = end;
+    }
+
+    sampled
+}
+
+/// Returns `x_range` with its unbounded ends replaced by the extent of the finite points of
+/// `data`.
+fn visible_span(data: &[(f64, f64)], x_range: Range<f64>) -> Range<f64> {
+    let (first, last) = data
+        .iter()
+        .filter(|point| point.0.is_finite() && point.1.is_finite())
+        .fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(first, last), point| (first.min(point.0), last.max(point.0)),
+        );
+    let start = x_range.start.min(x_range.end);
+    let end = x_range.start.max(x_range.end);
+
+    let start = if start.is_finite() { start } else { first };
+    let end = if end.is_finite() { end } else { last };
+
+    start..end
+}
+
+/// Returns the part of `budget` belonging to a run by the share of `x_range` it covers.
+fn share(run: &[(f64, f64)], x_range: &Range<f64>, budget: usize) -> usize {
+    let span = x_range.end - x_range.start;
+
+    let (Some(first), Some(last)) = (run.first(), run.last()) else {
+        return 0;
+    };
+
+    if span <= 0.0 || !span.is_finite() {
+        return budget;
+    }
+
+    let covered = first.0.max(last.0).min(x_range.end) - first.0.min(last.0).max(x_range.start);
+
+    (budget as f64 * (covered / span).clamp(0.0, 1.0)).ceil() as usize
+}
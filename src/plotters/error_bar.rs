@@ -0,0 +1,86 @@
+use super::{AxisValue, Extent};
+
+/// The error of a single value, measured from the value downwards and upwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ErrorValue {
+    pub minus: f64,
+    pub plus: f64,
+}
+
+impl ErrorValue {
+    /// Creates a symmetric error of `error` in both directions.
+    pub fn symmetric(error: impl AxisValue) -> Self {
+        let error = error.to_f64();
+
+        Self {
+            minus: error,
+            plus: error,
+        }
+    }
+
+    /// Creates an asymmetric error.
+    pub fn asymmetric(minus: impl AxisValue, plus: impl AxisValue) -> Self {
+        Self {
+            minus: minus.to_f64(),
+            plus: plus.to_f64(),
+        }
+    }
+}
+
+impl<T: AxisValue> From<T> for ErrorValue {
+    fn from(error: T) -> Self {
+        Self::symmetric(error)
+    }
+}
+
+impl<M: AxisValue, P: AxisValue> From<(M, P)> for ErrorValue {
+    fn from((minus, plus): (M, P)) -> Self {
+        Self::asymmetric(minus, plus)
+    }
+}
+
+/// The x and y errors of a series, one entry per data point.
+///
+/// Points without an entry are drawn without an error bar.
+#[derive(Debug, Clone)]
+pub struct ErrorBars {
+    pub x: Vec<ErrorValue>,
+    pub y: Vec<ErrorValue>,
+    pub whisker_width: u32,
+}
+
+impl ErrorBars {
+    const WHISKER_WIDTH_DEFAULT: u32 = 6;
+
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty() && self.y.is_empty()
+    }
+
+    /// Widens the x extent of the points by their largest errors.
+    pub(crate) fn x_bounds(&self, points: Extent) -> Extent {
+        widen(points, &self.x)
+    }
+
+    /// Widens the y extent of the points by their largest errors.
+    pub(crate) fn y_bounds(&self, points: Extent) -> Extent {
+        widen(points, &self.y)
+    }
+}
+
+impl Default for ErrorBars {
+    fn default() -> Self {
+        Self {
+            x: Vec::new(),
+            y: Vec::new(),
+            whisker_width: Self::WHISKER_WIDTH_DEFAULT,
+        }
+    }
+}
+
+fn widen(extent: Extent, errors: &[ErrorValue]) -> Extent {
+    let (minus, plus) = errors.iter().fold((0.0, 0.0), |(minus, plus), error| {
+        (error.minus.abs().max(minus), error.plus.abs().max(plus))
+    });
+
+    extent.widened(minus, plus)
+}
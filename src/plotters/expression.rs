@@ -0,0 +1,522 @@
+//! Math expressions typed in by users, like `sin(x) / x` or `2x^2 - 3t + 1`.
+//!
+//! An [`Expression`] is parsed once and evaluated for the variables `x` and `t`. It supports
+//! the operators `+`, `-`, `*`, `/`, `%` and `^`, multiplication by juxtaposition like in `2x`
+//! or `(x + 1)(x - 1)`, the constants `pi`, `tau`, `e` and `inf`, and these functions:
+//!
+//! - `sin`, `cos`, `tan`, `asin`, `acos`, `atan`, `sinh`, `cosh`, `tanh`
+//! - `sqrt`, `cbrt`, `exp`, `ln`, `log` (base 10), `log2`
+//! - `abs`, `sign`, `floor`, `ceil`, `round`
+//! - `atan2(y, x)`, `pow(a, b)`, `hypot(a, b)`, and `min` and `max` of any number of values
+use crate::Function;
+
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A parsed math expression of the variables `x` and `t`.
+#[derive(Debug, Clone)]
+pub struct Expression {
+    root: Node,
+}
+
+impl Expression {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            tokens,
+            next: 0,
+            end: input.len(),
+        };
+
+        let root = parser.sum()?;
+
+        match parser.peek() {
+            Some((_, position)) => Err(ParseError {
+                kind: ErrorKind::UnexpectedToken,
+                position,
+            }),
+            None => Ok(Self { root }),
+        }
+    }
+
+    /// Returns the value of the expression for the given variables.
+    pub fn eval(&self, x: f64, t: f64) -> f64 {
+        self.root.eval(x, t)
+    }
+
+    /// Returns whether the expression uses `variable`.
+    pub fn uses(&self, variable: Variable) -> bool {
+        self.root.uses(variable)
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::parse(input)
+    }
+}
+
+/// Plots the expression over `x`, with `t` set to `0`.
+impl From<Expression> for Function {
+    fn from(expression: Expression) -> Self {
+        Function::new(move |x| expression.eval(x, 0.0))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variable {
+    X,
+    T,
+}
+
+/// An error in the input of [`Expression::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    /// The byte range of the input the error was found at.
+    ///
+    /// It is empty and at the end of the input if the input ended early.
+    pub position: Range<usize>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.kind, self.position.start)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnexpectedCharacter(char),
+    UnexpectedToken,
+    UnexpectedEnd,
+    InvalidNumber,
+    UnclosedParenthesis,
+    UnknownVariable(String),
+    UnknownFunction(String),
+    /// A function was called with the wrong number of arguments.
+    Arguments {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedCharacter(c) => write!(f, "unexpected character '{c}'"),
+            Self::UnexpectedToken => write!(f, "unexpected token"),
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::InvalidNumber => write!(f, "invalid number"),
+            Self::UnclosedParenthesis => write!(f, "unclosed parenthesis"),
+            Self::UnknownVariable(name) => write!(f, "unknown variable '{name}'"),
+            Self::UnknownFunction(name) => write!(f, "unknown function '{name}'"),
+            Self::Arguments {
+                function,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{function} expects {expected} argument{}, found {found}",
+                if *expected == 1 { "" } else { "s" }
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Number(f64),
+    Variable(Variable),
+    Negate(Box<Node>),
+    Binary(Operator, Box<Node>, Box<Node>),
+    Unary(fn(f64) -> f64, Box<Node>),
+    Call(fn(f64, f64) -> f64, Box<Node>, Box<Node>),
+}
+
+impl Node {
+    fn eval(&self, x: f64, t: f64) -> f64 {
+        match self {
+            Node::Number(value) => *value,
+            Node::Variable(Variable::X) => x,
+            Node::Variable(Variable::T) => t,
+            Node::Negate(node) => -node.eval(x, t),
+            Node::Binary(operator, a, b) => operator.apply(a.eval(x, t), b.eval(x, t)),
+            Node::Unary(f, a) => f(a.eval(x, t)),
+            Node::Call(f, a, b) => f(a.eval(x, t), b.eval(x, t)),
+        }
+    }
+
+    fn uses(&self, variable: Variable) -> bool {
+        match self {
+            Node::Number(_) => false,
+            Node::Variable(used) => *used == variable,
+            Node::Negate(a) | Node::Unary(_, a) => a.uses(variable),
+            Node::Binary(_, a, b) | Node::Call(_, a, b) => a.uses(variable) || b.uses(variable),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+}
+
+impl Operator {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        match self {
+            Operator::Add => a + b,
+            Operator::Subtract => a - b,
+            Operator::Multiply => a * b,
+            Operator::Divide => a / b,
+            Operator::Remainder => a % b,
+            Operator::Power => a.powf(b),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Builtin {
+    Unary(fn(f64) -> f64),
+    Binary(fn(f64, f64) -> f64),
+    /// A function of one or more values, folded pairwise.
+    Variadic(fn(f64, f64) -> f64),
+}
+
+const FUNCTIONS: &[(&str, Builtin)] = &[
+    ("sin", Builtin::Unary(f64::sin)),
+    ("cos", Builtin::Unary(f64::cos)),
+    ("tan", Builtin::Unary(f64::tan)),
+    ("asin", Builtin::Unary(f64::asin)),
+    ("acos", Builtin::Unary(f64::acos)),
+    ("atan", Builtin::Unary(f64::atan)),
+    ("sinh", Builtin::Unary(f64::sinh)),
+    ("cosh", Builtin::Unary(f64::cosh)),
+    ("tanh", Builtin::Unary(f64::tanh)),
+    ("sqrt", Builtin::Unary(f64::sqrt)),
+    ("cbrt", Builtin::Unary(f64::cbrt)),
+    ("exp", Builtin::Unary(f64::exp)),
+    ("ln", Builtin::Unary(f64::ln)),
+    ("log", Builtin::Unary(f64::log10)),
+    ("log2", Builtin::Unary(f64::log2)),
+    ("abs", Builtin::Unary(f64::abs)),
+    ("sign", Builtin::Unary(sign)),
+    ("floor", Builtin::Unary(f64::floor)),
+    ("ceil", Builtin::Unary(f64::ceil)),
+    ("round", Builtin::Unary(f64::round)),
+    ("atan2", Builtin::Binary(f64::atan2)),
+    ("pow", Builtin::Binary(f64::powf)),
+    ("hypot", Builtin::Binary(f64::hypot)),
+    ("min", Builtin::Variadic(f64::min)),
+    ("max", Builtin::Variadic(f64::max)),
+];
+
+const CONSTANTS: &[(&str, f64)] = &[
+    ("pi", std::f64::consts::PI),
+    ("π", std::f64::consts::PI),
+    ("tau", std::f64::consts::TAU),
+    ("e", std::f64::consts::E),
+    ("inf", f64::INFINITY),
+];
+
+/// Returns the variable or constant called `name`.
+fn value(name: &str) -> Option<Node> {
+    match name {
+        "x" => Some(Node::Variable(Variable::X)),
+        "t" => Some(Node::Variable(Variable::T)),
+        _ => CONSTANTS
+            .iter()
+            .find(|(constant, _)| *constant == name)
+            .map(|(_, value)| Node::Number(*value)),
+    }
+}
+
+/// Returns `-1`, `0` or `1`, unlike [`f64::signum`] which never returns `0`.
+fn sign(value: f64) -> f64 {
+    if value == 0.0 { 0.0 } else { value.signum() }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Number(f64),
+    Identifier(&'a str),
+    /// One of `+ - * / % ^ ( ) ,`.
+    Symbol(char),
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token<'_>, Range<usize>)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let mut end = start + c.len_utf8();
+
+        let token = if c.is_whitespace() {
+            continue;
+        } else if c.is_ascii_digit() || c == '.' {
+            while let Some(&(i, c)) = chars.peek() {
+                // An exponent needs digits, so that `2e` is read as 2 times e
+                let exponent = matches!(c, 'e' | 'E') && {
+                    let rest = &input[i + 1..];
+                    let rest = rest.strip_prefix(['+', '-']).unwrap_or(rest);
+
+                    rest.starts_with(|c: char| c.is_ascii_digit())
+                };
+
+                if c.is_ascii_digit() || c == '.' {
+                    end = i + 1;
+                    chars.next();
+                } else if exponent {
+                    end = i + 1;
+                    chars.next();
+
+                    if let Some(&(i, '+' | '-')) = chars.peek() {
+                        end = i + 1;
+                        chars.next();
+                    }
+                } else {
+                    break;
+                }
+            }
+
+            let value = input[start..end].parse().map_err(|_| ParseError {
+                kind: ErrorKind::InvalidNumber,
+                position: start..end,
+            })?;
+
+            Token::Number(value)
+        } else if c.is_alphabetic() || c == '_' {
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+
+                end = i + c.len_utf8();
+                chars.next();
+            }
+
+            Token::Identifier(&input[start..end])
+        } else if "+-*/%^(),".contains(c) {
+            Token::Symbol(c)
+        } else {
+            return Err(ParseError {
+                kind: ErrorKind::UnexpectedCharacter(c),
+                position: start..end,
+            });
+        };
+
+        tokens.push((token, start..end));
+    }
+
+    Ok(tokens)
+}
+
+/// A recursive descent parser, with a method per level of precedence.
+struct Parser<'a> {
+    tokens: Vec<(Token<'a>, Range<usize>)>,
+    next: usize,
+    /// The length of the input.
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<(Token<'a>, Range<usize>)> {
+        self.tokens.get(self.next).cloned()
+    }
+
+    fn advance(&mut self) -> Result<(Token<'a>, Range<usize>), ParseError> {
+        let token = self.peek().ok_or(ParseError {
+            kind: ErrorKind::UnexpectedEnd,
+            position: self.end..self.end,
+        })?;
+        self.next += 1;
+
+        Ok(token)
+    }
+
+    /// Consumes the next token if it is `symbol`.
+    fn eat(&mut self, symbol: char) -> bool {
+        let found = matches!(self.peek(), Some((Token::Symbol(c), _)) if c == symbol);
+        self.next += usize::from(found);
+
+        found
+    }
+
+    /// `sum = product (("+" | "-") product)*`
+    fn sum(&mut self) -> Result<Node, ParseError> {
+        let mut node = self.product()?;
+
+        loop {
+            let operator = if self.eat('+') {
+                Operator::Add
+            } else if self.eat('-') {
+                Operator::Subtract
+            } else {
+                return Ok(node);
+            };
+
+            node = Node::Binary(operator, Box::new(node), Box::new(self.product()?));
+        }
+    }
+
+    /// `product = unary (("*" | "/" | "%") unary | power)*`
+    ///
+    /// A power directly following a factor, like in `2x` or `3(x + 1)`, multiplies it.
+    fn product(&mut self) -> Result<Node, ParseError> {
+        let mut node = self.unary()?;
+
+        loop {
+            let (operator, factor) = if self.eat('*') {
+                (Operator::Multiply, self.unary()?)
+            } else if self.eat('/') {
+                (Operator::Divide, self.unary()?)
+            } else if self.eat('%') {
+                (Operator::Remainder, self.unary()?)
+            } else if let Some((Token::Identifier(_) | Token::Symbol('('), _)) = self.peek() {
+                (Operator::Multiply, self.power()?)
+            } else {
+                return Ok(node);
+            };
+
+            node = Node::Binary(operator, Box::new(node), Box::new(factor));
+        }
+    }
+
+    /// `unary = ("-" | "+") unary | power`
+    fn unary(&mut self) -> Result<Node, ParseError> {
+        if self.eat('-') {
+            Ok(Node::Negate(Box::new(self.unary()?)))
+        } else if self.eat('+') {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    /// `power = primary ("^" unary)?`, so that `-x^2` is `-(x^2)` and `2^-x` is `2^(-x)`.
+    fn power(&mut self) -> Result<Node, ParseError> {
+        let base = self.primary()?;
+
+        if self.eat('^') {
+            Ok(Node::Binary(
+                Operator::Power,
+                Box::new(base),
+                Box::new(self.unary()?),
+            ))
+        } else {
+            Ok(base)
+        }
+    }
+
+    /// `primary = number | name | name "(" arguments ")" | "(" sum ")"`
+    fn primary(&mut self) -> Result<Node, ParseError> {
+        let (token, position) = self.advance()?;
+
+        match token {
+            Token::Number(value) => Ok(Node::Number(value)),
+            // Variables and constants before a parenthesis are multiplied, like in `x(x + 1)`
+            Token::Identifier(name) => match value(name) {
+                Some(node) => Ok(node),
+                None if self.eat('(') => self.call(name, position),
+                None => Err(ParseError {
+                    kind: ErrorKind::UnknownVariable(name.to_string()),
+                    position,
+                }),
+            },
+            Token::Symbol('(') => {
+                let node = self.sum()?;
+                self.close(position)?;
+
+                Ok(node)
+            }
+            Token::Symbol(_) => Err(ParseError {
+                kind: ErrorKind::UnexpectedToken,
+                position,
+            }),
+        }
+    }
+
+    /// Parses the arguments of the function `name` up to the closing parenthesis.
+    fn call(&mut self, name: &str, position: Range<usize>) -> Result<Node, ParseError> {
+        let open = self.tokens[self.next - 1].1.clone();
+        let mut arguments = Vec::new();
+
+        if !self.eat(')') {
+            loop {
+                arguments.push(self.sum()?);
+
+                if !self.eat(',') {
+                    break;
+                }
+            }
+
+            self.close(open)?;
+        }
+
+        let Some((_, builtin)) = FUNCTIONS.iter().find(|(function, _)| *function == name) else {
+            return Err(ParseError {
+                kind: ErrorKind::UnknownFunction(name.to_string()),
+                position,
+            });
+        };
+
+        // The error spans the whole call
+        let found = arguments.len();
+        let error = |expected| ParseError {
+            kind: ErrorKind::Arguments {
+                function: name.to_string(),
+                expected,
+                found,
+            },
+            position: position.start..self.tokens[self.next - 1].1.end,
+        };
+
+        match *builtin {
+            Builtin::Unary(f) => match <[Node; 1]>::try_from(arguments) {
+                Ok([a]) => Ok(Node::Unary(f, Box::new(a))),
+                Err(_) => Err(error(1)),
+            },
+            Builtin::Binary(f) => match <[Node; 2]>::try_from(arguments) {
+                Ok([a, b]) => Ok(Node::Call(f, Box::new(a), Box::new(b))),
+                Err(_) => Err(error(2)),
+            },
+            Builtin::Variadic(f) => {
+                let mut arguments = arguments.into_iter();
+                let first = arguments.next().ok_or_else(|| error(1))?;
+
+                Ok(arguments.fold(first, |node, argument| {
+                    Node::Call(f, Box::new(node), Box::new(argument))
+                }))
+            }
+        }
+    }
+
+    /// Consumes the parenthesis closing the one at `open`.
+    fn close(&mut self, open: Range<usize>) -> Result<(), ParseError> {
+        if self.eat(')') {
+            return Ok(());
+        }
+
+        Err(match self.peek() {
+            Some((_, position)) => ParseError {
+                kind: ErrorKind::UnexpectedToken,
+                position,
+            },
+            None => ParseError {
+                kind: ErrorKind::UnclosedParenthesis,
+                position: open,
+            },
+        })
+    }
+}
@@ -0,0 +1,410 @@
+use super::scale::Extent;
+use super::{AxisValue, DataSource, Downsample, LineSeries, cull};
+
+use std::borrow::Cow;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// The number of pixel columns sampled when no resolution is requested.
+const COLUMNS: usize = 1000;
+/// The number of samples the automatic ranges are fitted to.
+const BOUNDS_SAMPLES: usize = 256;
+
+/// A function `y = f(x)`, sampled over the visible part of the x axis.
+///
+/// The samples are refined where the curve bends and the line is broken at jumps, asymptotes
+/// and values which are not finite.
+#[derive(Clone)]
+pub struct Function {
+    f: Rc<dyn Fn(f64) -> f64>,
+    domain: Option<Range<f64>>,
+}
+
+impl Function {
+    pub fn new(f: impl Fn(f64) -> f64 + 'static) -> Self {
+        Self {
+            f: Rc::new(f),
+            domain: None,
+        }
+    }
+
+    /// Restricts the function to `domain`, which automatic ranges are then fitted to.
+    ///
+    /// Without a domain, only the automatic y range is fitted to the function over the visible
+    /// x range.
+    pub fn domain(mut self, domain: Range<impl AxisValue>) -> Self {
+        self.domain = Some(domain.start.to_f64()..domain.end.to_f64());
+        self
+    }
+}
+
+impl DataSource for Function {
+    fn bounds(&self) -> (Extent, Extent) {
+        let Some(domain) = &self.domain else {
+            return (Extent::EMPTY, Extent::EMPTY);
+        };
+
+        uniform(&|x| (x, (self.f)(x)), domain.clone())
+    }
+
+    /// Without a domain, the function is sampled over the visible x range.
+    fn y_bounds(&self, x_range: Range<f64>) -> Extent {
+        if self.domain.is_some() {
+            return self.bounds().1;
+        }
+
+        intersect(x_range, f64::NEG_INFINITY..f64::INFINITY).map_or(Extent::EMPTY, |range| {
+            uniform(&|x| (x, (self.f)(x)), range).1
+        })
+    }
+
+    fn points(&self, x_range: Range<f64>) -> Cow<'_, [(f64, f64)]> {
+        self.downsampled(x_range, COLUMNS, Downsample::default())
+    }
+
+    fn downsampled(
+        &self,
+        x_range: Range<f64>,
+        columns: usize,
+        _downsample: Downsample,
+    ) -> Cow<'_, [(f64, f64)]> {
+        let domain = self
+            .domain
+            .clone()
+            .unwrap_or(f64::NEG_INFINITY..f64::INFINITY);
+
+        match intersect(x_range, domain) {
+            Some(range) => Cow::Owned(sample(&|x| (x, (self.f)(x)), range, columns)),
+            None => Cow::Borrowed(&[]),
+        }
+    }
+}
+
+/// A parametric curve `t -> (x, y)`, sampled over a range of `t`.
+///
+/// The whole range of `t` is sampled for every frame, and only the parts of the curve within
+/// the visible x range are drawn.
+#[derive(Clone)]
+pub struct Parametric {
+    f: Rc<dyn Fn(f64) -> (f64, f64)>,
+    t_range: Range<f64>,
+}
+
+impl Parametric {
+    pub fn new(f: impl Fn(f64) -> (f64, f64) + 'static, t_range: Range<impl AxisValue>) -> Self {
+        Self {
+            f: Rc::new(f),
+            t_range: t_range.start.to_f64()..t_range.end.to_f64(),
+        }
+    }
+}
+
+impl DataSource for Parametric {
+    fn bounds(&self) -> (Extent, Extent) {
+        uniform(self.f.as_ref(), self.t_range.clone())
+    }
+
+    /// Returns the parts of the curve within `x_range`, which it may leave and reenter.
+    fn points(&self, x_range: Range<f64>) -> Cow<'_, [(f64, f64)]> {
+        self.downsampled(x_range, COLUMNS, Downsample::default())
+    }
+
+    fn downsampled(
+        &self,
+        x_range: Range<f64>,
+        columns: usize,
+        _downsample: Downsample,
+    ) -> Cow<'_, [(f64, f64)]> {
+        let curve = sample(self.f.as_ref(), self.t_range.clone(), columns);
+
+        Cow::Owned(cull::crop(&curve, x_range))
+    }
+}
+
+/// The curve where `f(x, y) = 0` within a region, traced with marching squares.
+#[derive(Clone)]
+pub struct Implicit {
+    f: Rc<dyn Fn(f64, f64) -> f64>,
+    x_range: Range<f64>,
+    y_range: Range<f64>,
+}
+
+impl Implicit {
+    /// The number of pixels covered by a cell of the grid.
+    const CELL_SIZE: usize = 4;
+
+    pub fn new(
+        f: impl Fn(f64, f64) -> f64 + 'static,
+        x_range: Range<impl AxisValue>,
+        y_range: Range<impl AxisValue>,
+    ) -> Self {
+        Self {
+            f: Rc::new(f),
+            x_range: x_range.start.to_f64()..x_range.end.to_f64(),
+            y_range: y_range.start.to_f64()..y_range.end.to_f64(),
+        }
+    }
+}
+
+impl DataSource for Implicit {
+    /// Returns the region the curve is searched in.
+    fn bounds(&self) -> (Extent, Extent) {
+        (
+            Extent::EMPTY.include(self.x_range.start..self.x_range.end),
+            Extent::EMPTY.include(self.y_range.start..self.y_range.end),
+        )
+    }
+
+    fn points(&self, x_range: Range<f64>) -> Cow<'_, [(f64, f64)]> {
+        self.downsampled(x_range, COLUMNS, Downsample::default())
+    }
+
+    fn downsampled(
+        &self,
+        x_range: Range<f64>,
+        columns: usize,
+        _downsample: Downsample,
+    ) -> Cow<'_, [(f64, f64)]> {
+        let Some(x_range) = intersect(x_range, self.x_range.clone()) else {
+            return Cow::Borrowed(&[]);
+        };
+        let cells = (columns / Self::CELL_SIZE).max(8);
+
+        Cow::Owned(march(
+            self.f.as_ref(),
+            (x_range, self.y_range.clone()),
+            cells,
+        ))
+    }
+}
+
+/// Plots `y = f(x)` over the visible x range.
+pub fn function_series(f: impl Fn(f64) -> f64 + 'static) -> LineSeries {
+    LineSeries::from_source(Function::new(f))
+}
+
+/// Plots the curve `t -> (x, y)` for `t` in `t_range`.
+///
+/// The whole range of `t` is sampled for every frame, however far the chart is zoomed in.
+pub fn parametric_series(
+    f: impl Fn(f64) -> (f64, f64) + 'static,
+    t_range: Range<impl AxisValue>,
+) -> LineSeries {
+    LineSeries::from_source(Parametric::new(f, t_range))
+}
+
+/// Plots the curve where `f(x, y) = 0` within the given region.
+pub fn implicit_series(
+    f: impl Fn(f64, f64) -> f64 + 'static,
+    x_range: Range<impl AxisValue>,
+    y_range: Range<impl AxisValue>,
+) -> LineSeries {
+    LineSeries::from_source(Implicit::new(f, x_range, y_range))
+}
+
+/// Returns the part of `x_range` within `domain`, if it is finite and not empty.
+fn intersect(x_range: Range<f64>, domain: Range<f64>) -> Option<Range<f64>> {
+    let start = x_range.start.max(domain.start.min(domain.end));
+    let end = x_range.end.min(domain.start.max(domain.end));
+
+    (start.is_finite() && end.is_finite() && start < end).then_some(start..end)
+}
+
+/// Returns the extent of a curve sampled at evenly spaced values of `t`.
+fn uniform(curve: &dyn Fn(f64) -> (f64, f64), t_range: Range<f64>) -> (Extent, Extent) {
+    let step = (t_range.end - t_range.start) / BOUNDS_SAMPLES as f64;
+
+    (0..=BOUNDS_SAMPLES)
+        .map(|i| curve(t_range.start + step * i as f64))
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .fold((Extent::EMPTY, Extent::EMPTY), |(x, y), point| {
+            (x.include(point.0..point.0), y.include(point.1..point.1))
+        })
+}
+
+/// Samples a curve over `t_range` for a line `columns` pixels wide.
+///
+/// Starting from evenly spaced samples, every interval is halved until a line between its ends
+/// is off by less than half a pixel. Intervals which still jump by many pixels at the finest
+/// level are broken with a NaN point.
+fn sample(
+    curve: &dyn Fn(f64) -> (f64, f64),
+    t_range: Range<f64>,
+    columns: usize,
+) -> Vec<(f64, f64)> {
+    let count = (columns / 4).max(8);
+    let step = (t_range.end - t_range.start) / count as f64;
+    let initial: Vec<_> = (0..=count)
+        .map(|i| t_range.start + step * i as f64)
+        .map(|t| (t, finite(curve(t))))
+        .collect();
+
+    let columns = columns.max(1) as f64;
+    let mut sampler = Sampler {
+        curve,
+        pixel: (
+            typical_span(initial.iter().map(|(_, point)| point.0)) / columns,
+            typical_span(initial.iter().map(|(_, point)| point.1)) / columns,
+        ),
+        points: vec![initial[0].1],
+    };
+
+    for pair in initial.windows(2) {
+        sampler.refine(pair[0], pair[1], 0);
+    }
+
+    sampler.points
+}
+
+struct Sampler<'a> {
+    curve: &'a dyn Fn(f64) -> (f64, f64),
+    /// The size of a pixel in data units, in x and y.
+    pixel: (f64, f64),
+    points: Vec<(f64, f64)>,
+}
+
+impl Sampler<'_> {
+    const MAX_DEPTH: usize = 6;
+    /// The largest distance of a sample from the line through its neighbours, in pixels.
+    const TOLERANCE: f64 = 0.5;
+    /// The smallest distance between two samples at the finest level which breaks the line, in
+    /// pixels.
+    const JUMP: f64 = 32.0;
+    /// The share of a jump which one half of its interval has to make to break the line.
+    ///
+    /// A steep but continuous curve spreads its change over both halves, while the value at a
+    /// discontinuity is on either side of it and one beyond an asymptote is not in between.
+    const UNEVEN: f64 = 0.9;
+
+    /// Pushes the samples after `start` up to and including `end`.
+    fn refine(&mut self, start: (f64, (f64, f64)), end: (f64, (f64, f64)), depth: usize) {
+        let (t0, p0) = start;
+        let (t1, p1) = end;
+        let t = (t0 + t1) / 2.0;
+        let middle = finite((self.curve)(t));
+        let chord = ((p0.0 + p1.0) / 2.0, (p0.1 + p1.1) / 2.0);
+
+        // NaN distances are never within the tolerance
+        if self.distance(middle, chord) <= Self::TOLERANCE {
+            self.points.push(p1);
+        } else if depth == Self::MAX_DEPTH {
+            let jump = self.distance(p0, p1);
+            let half = self.distance(p0, middle).max(self.distance(middle, p1));
+
+            if jump > Self::JUMP && half > jump * Self::UNEVEN {
+                self.points.push((f64::NAN, f64::NAN));
+            } else {
+                self.points.push(middle);
+            }
+
+            self.points.push(p1);
+        } else {
+            self.refine(start, (t, middle), depth + 1);
+            self.refine((t, middle), end, depth + 1);
+        }
+    }
+
+    fn distance(&self, a: (f64, f64), b: (f64, f64)) -> f64 {
+        ((a.0 - b.0) / self.pixel.0)
+            .abs()
+            .max(((a.1 - b.1) / self.pixel.1).abs())
+    }
+}
+
+/// Replaces a point with values which are not finite by a break of the line.
+fn finite((x, y): (f64, f64)) -> (f64, f64) {
+    if x.is_finite() && y.is_finite() {
+        (x, y)
+    } else {
+        (x, f64::NAN)
+    }
+}
+
+/// Returns the span of the finite values without the outer 5% on either side, so samples close
+/// to an asymptote do not make every pixel huge.
+fn typical_span(values: impl Iterator<Item = f64>) -> f64 {
+    let mut values: Vec<f64> = values.filter(|value| value.is_finite()).collect();
+    values.sort_by(f64::total_cmp);
+
+    let (Some(first), Some(last)) = (values.first(), values.last()) else {
+        return 1.0;
+    };
+    let outer = values.len() / 20;
+    let span = values[values.len() - 1 - outer] - values[outer];
+
+    if span > 0.0 {
+        span
+    } else if last > first {
+        last - first
+    } else {
+        1.0
+    }
+}
+
+/// Traces the zero crossings of `f` through a grid of `cells` by `cells` cells over `region`.
+///
+/// Every cell contributes its own segments, separated by NaN points.
+fn march(
+    f: &dyn Fn(f64, f64) -> f64,
+    (x_range, y_range): (Range<f64>, Range<f64>),
+    cells: usize,
+) -> Vec<(f64, f64)> {
+    let x = |i: usize| x_range.start + (x_range.end - x_range.start) * i as f64 / cells as f64;
+    let y = |j: usize| y_range.start + (y_range.end - y_range.start) * j as f64 / cells as f64;
+    let values: Vec<Vec<f64>> = (0..=cells)
+        .map(|j| (0..=cells).map(|i| f(x(i), y(j))).collect())
+        .collect();
+
+    let mut points = Vec::new();
+
+    for j in 0..cells {
+        for i in 0..cells {
+            // The corners in counterclockwise order, starting at the bottom left
+            let corners = [
+                ((x(i), y(j)), values[j][i]),
+                ((x(i + 1), y(j)), values[j][i + 1]),
+                ((x(i + 1), y(j + 1)), values[j + 1][i + 1]),
+                ((x(i), y(j + 1)), values[j + 1][i]),
+            ];
+
+            if corners.iter().any(|(_, value)| !value.is_finite()) {
+                continue;
+            }
+
+            // The crossings on the bottom, right, top and left edge
+            let crossings: Vec<(f64, f64)> = (0..4)
+                .filter_map(|edge| {
+                    let (a, value_a) = corners[edge];
+                    let (b, value_b) = corners[(edge + 1) % 4];
+
+                    ((value_a > 0.0) != (value_b > 0.0)).then(|| {
+                        let t = value_a / (value_a - value_b);
+
+                        (a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+                    })
+                })
+                .collect();
+
+            let segments = match crossings[..] {
+                [a, b] => vec![(a, b)],
+                // A saddle, resolved by the value at the center of the cell
+                [bottom, right, top, left] => {
+                    let center = f((x(i) + x(i + 1)) / 2.0, (y(j) + y(j + 1)) / 2.0);
+
+                    if (center > 0.0) == (corners[0].1 > 0.0) {
+                        vec![(bottom, right), (top, left)]
+                    } else {
+                        vec![(left, bottom), (right, top)]
+                    }
+                }
+                _ => continue,
+            };
+
+            for (a, b) in segments {
+                points.extend([a, b, (f64::NAN, f64::NAN)]);
+            }
+        }
+    }
+
+    points
+}
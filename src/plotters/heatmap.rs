@@ -0,0 +1,243 @@
+use super::colormap::Colormap;
+use super::scale::Extent;
+use super::{AxisValue, Color};
+
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters::style::Color as _;
+use plotters_backend::text_anchor::{HPos, Pos, VPos};
+
+use std::ops::Range;
+
+/// A regular grid of values, drawn as colored cells.
+#[derive(Clone)]
+pub struct HeatmapSeries {
+    pub x_edges: Vec<f64>,
+    pub y_edges: Vec<f64>,
+    /// The cell values in row-major order, one row per pair of adjacent y edges.
+    pub values: Vec<f64>,
+    pub colormap: Colormap,
+    pub log_scale: bool,
+    pub value_range: Option<Range<f64>>,
+    pub colorbar: bool,
+}
+
+impl HeatmapSeries {
+    pub(crate) const COLORBAR_AREA_SIZE: u32 = 70;
+    const COLORBAR_WIDTH: i32 = 15;
+    const COLORBAR_GAP: i32 = 10;
+    const COLORBAR_STEPS: i32 = 64;
+    const COLORBAR_TICKS: usize = 5;
+
+    pub fn new(
+        x_edges: impl IntoIterator<Item = impl AxisValue>,
+        y_edges: impl IntoIterator<Item = impl AxisValue>,
+        values: impl IntoIterator<Item = impl AxisValue>,
+    ) -> Self {
+        Self {
+            x_edges: x_edges.into_iter().map(AxisValue::to_f64).collect(),
+            y_edges: y_edges.into_iter().map(AxisValue::to_f64).collect(),
+            values: values.into_iter().map(AxisValue::to_f64).collect(),
+            colormap: Colormap::default(),
+            log_scale: false,
+            value_range: None,
+            colorbar: true,
+        }
+    }
+
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Maps values logarithmically onto the colormap, non-positive values are not drawn.
+    pub fn log_scale(mut self, log_scale: bool) -> Self {
+        self.log_scale = log_scale;
+        self
+    }
+
+    /// Sets the values mapped to both ends of the colormap instead of the data minimum and maximum.
+    ///
+    /// With a log scale, a non-positive start falls back to the smallest positive value.
+    pub fn value_range(mut self, range: Range<impl AxisValue>) -> Self {
+        self.value_range = Some(range.start.to_f64()..range.end.to_f64());
+        self
+    }
+
+    pub fn colorbar(mut self, colorbar: bool) -> Self {
+        self.colorbar = colorbar;
+        self
+    }
+
+    /// Iterates over the corners and value of every cell.
+    pub(crate) fn cells(&self) -> impl Iterator<Item = ((f64, f64), (f64, f64), f64)> + '_ {
+        let columns = self.x_edges.len().saturating_sub(1);
+
+        self.y_edges
+            .windows(2)
+            .enumerate()
+            .flat_map(move |(row, y)| {
+                self.x_edges
+                    .windows(2)
+                    .enumerate()
+                    .filter_map(move |(column, x)| {
+                        let value = *self.values.get(row * columns + column)?;
+
+                        Some(((x[0], y[0]), (x[1], y[1]), value))
+                    })
+            })
+    }
+
+    pub(crate) fn x_bounds(&self) -> Extent {
+        bounds(&self.x_edges)
+    }
+
+    pub(crate) fn y_bounds(&self) -> Extent {
+        bounds(&self.y_edges)
+    }
+
+    /// Returns the color of a cell or `None` if the value can not be drawn.
+    pub(crate) fn color(&self, value: f64, range: &Range<f64>) -> Option<Color> {
+        let value = self.scale(value)?;
+        let t = if range.end > range.start {
+            (value - range.start) / (range.end - range.start)
+        } else {
+            0.5
+        };
+
+        Some(self.colormap.sample(t as f32))
+    }
+
+    /// Returns the values mapped to both ends of the colormap.
+    pub fn color_range(&self) -> Range<f64> {
+        let range = self.scaled_range();
+
+        self.unscale(range.start)..self.unscale(range.end)
+    }
+
+    /// Returns the scaled value range mapped onto the colormap.
+    pub(crate) fn scaled_range(&self) -> Range<f64> {
+        let data = self
+            .values
+            .iter()
+            .filter_map(|value| self.scale(*value))
+            .fold(f64::INFINITY..f64::NEG_INFINITY, |range, value| {
+                range.start.min(value)..range.end.max(value)
+            });
+        let data = if data.start > data.end {
+            0.0..1.0
+        } else {
+            data
+        };
+
+        // Ends which can not be scaled, like non-positive ones on a log scale, fall back to the
+        // data
+        match &self.value_range {
+            Some(range) => {
+                self.scale(range.start).unwrap_or(data.start)
+                    ..self.scale(range.end).unwrap_or(data.end)
+            }
+            None => data,
+        }
+    }
+
+    fn scale(&self, value: f64) -> Option<f64> {
+        if !value.is_finite() {
+            return None;
+        }
+
+        if self.log_scale {
+            (value > 0.0).then(|| value.log10())
+        } else {
+            Some(value)
+        }
+    }
+
+    fn unscale(&self, value: f64) -> f64 {
+        if self.log_scale {
+            10f64.powf(value)
+        } else {
+            value
+        }
+    }
+
+    /// Draws the colorbar right next to the plotting area in screen coordinates.
+    pub(crate) fn draw_colorbar<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        plotting_area: (Range<i32>, Range<i32>),
+        label_style: &TextStyle,
+        text_color: Color,
+    ) {
+        let (x_pixels, y_pixels) = plotting_area;
+        let line_style = RGBAColor::from(text_color).stroke_width(1);
+        let range = self.scaled_range();
+
+        let left = x_pixels.end + Self::COLORBAR_GAP;
+        let right = left + Self::COLORBAR_WIDTH;
+        let height = y_pixels.end - y_pixels.start;
+
+        for step in 0..Self::COLORBAR_STEPS {
+            let top = y_pixels.end - height * (step + 1) / Self::COLORBAR_STEPS;
+            let bottom = y_pixels.end - height * step / Self::COLORBAR_STEPS;
+            let t = (step as f32 + 0.5) / Self::COLORBAR_STEPS as f32;
+            let color: RGBAColor = self.colormap.sample(t).into();
+
+            area.draw(&Rectangle::new(
+                [(left, top), (right, bottom)],
+                color.filled(),
+            ))
+            .unwrap();
+        }
+
+        area.draw(&Rectangle::new(
+            [(left, y_pixels.start), (right, y_pixels.end)],
+            line_style,
+        ))
+        .unwrap();
+
+        let label_style = label_style.pos(Pos::new(HPos::Left, VPos::Center));
+
+        for tick in 0..Self::COLORBAR_TICKS {
+            let t = tick as f32 / (Self::COLORBAR_TICKS - 1) as f32;
+            let value = self.unscale(range.start + (range.end - range.start) * f64::from(t));
+            let y = y_pixels.end - (height as f32 * t) as i32;
+
+            area.draw(&PathElement::new(
+                vec![(right, y), (right + 3, y)],
+                line_style,
+            ))
+            .unwrap();
+            area.draw(&Text::new(
+                format_tick(value),
+                (right + 5, y),
+                label_style.clone(),
+            ))
+            .unwrap();
+        }
+    }
+}
+
+pub fn heatmap_series(
+    x_edges: impl IntoIterator<Item = impl AxisValue>,
+    y_edges: impl IntoIterator<Item = impl AxisValue>,
+    values: impl IntoIterator<Item = impl AxisValue>,
+) -> HeatmapSeries {
+    HeatmapSeries::new(x_edges, y_edges, values)
+}
+
+fn bounds(edges: &[f64]) -> Extent {
+    edges
+        .iter()
+        .fold(Extent::EMPTY, |extent, &edge| extent.include(edge..edge))
+}
+
+fn format_tick(value: f64) -> String {
+    let magnitude = value.abs();
+
+    if magnitude != 0.0 && !(1e-2..1e4).contains(&magnitude) {
+        format!("{value:.1e}")
+    } else {
+        format!("{value:.2}")
+    }
+}
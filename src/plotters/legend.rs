@@ -0,0 +1,201 @@
+use iced::{Padding, Point, Rectangle};
+use plotters::chart::SeriesLabelPosition;
+
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Where the legend of a [`Chart`] is placed.
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LegendPosition {
+    UpperLeft,
+    #[default]
+    UpperRight,
+    LowerLeft,
+    LowerRight,
+    /// Next to the plotting area, on the right side of the chart.
+    OutsideRight,
+    /// Below the plotting area, at the bottom of the chart.
+    OutsideBottom,
+}
+
+/// A click on an entry of the legend.
+///
+/// Series are identified by the order in which they were pushed to the [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegendEvent {
+    /// The entry was clicked to hide or show its series.
+    Toggled(usize),
+    /// The entry was double clicked to show only its series.
+    Isolated(usize),
+}
+
+/// The legend of a [`Chart`], listing every series with a label.
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Legend {
+    pub position: LegendPosition,
+    /// The background of the legend box, or `None` to use the [`Style`](crate::Style).
+    pub background: Option<iced::Color>,
+    /// The border of the legend box, or `None` to use the [`Style`](crate::Style).
+    pub border: Option<iced::Color>,
+    /// The space between the border and the entries in pixels.
+    pub padding: u32,
+    /// The width of the glyph drawn in front of each label in pixels.
+    pub glyph_width: u32,
+}
+
+impl Legend {
+    pub(crate) const FONT_SIZE: u32 = 12;
+    const GAP: i32 = 10;
+
+    pub fn new(position: LegendPosition) -> Self {
+        Self {
+            position,
+            background: None,
+            border: None,
+            padding: 5,
+            glyph_width: 20,
+        }
+    }
+
+    pub fn position(mut self, position: LegendPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn background(mut self, color: impl Into<iced::Color>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    pub fn border(mut self, color: impl Into<iced::Color>) -> Self {
+        self.border = Some(color.into());
+        self
+    }
+
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn glyph_width(mut self, width: u32) -> Self {
+        self.glyph_width = width;
+        self
+    }
+
+    /// Returns the size of the legend box in pixels, for labels as wide as `measure` returns.
+    pub(crate) fn size<'a>(
+        &self,
+        labels: impl Iterator<Item = &'a str>,
+        measure: impl Fn(&str) -> f32,
+    ) -> (i32, i32) {
+        let (lines, width) = labels.fold((0, 0.0), |(lines, width): (usize, f32), label| {
+            (lines + 1, width.max(measure(label)))
+        });
+
+        let text_width = width.ceil() as i32;
+        let text_height = (lines as f32 * Self::FONT_SIZE as f32 * 1.25).ceil() as i32;
+        let padding = self.padding as i32;
+
+        (
+            text_width + self.glyph_width as i32 + padding * 2,
+            text_height + padding * 2,
+        )
+    }
+
+    /// Returns the hit region of an entry in widget coordinates.
+    ///
+    /// `glyph` is the position plotters draws the glyph of the entry at, relative to `origin`.
+    pub(crate) fn entry_bounds(
+        &self,
+        glyph: (i32, i32),
+        origin: (i32, i32),
+        width: i32,
+    ) -> Rectangle {
+        let line_height = Self::FONT_SIZE as f32 * 1.25;
+        let padding = self.padding as f32;
+
+        Rectangle {
+            x: (origin.0 + glyph.0) as f32 - padding,
+            y: (origin.1 + glyph.1) as f32 - line_height / 2.0,
+            width: width as f32,
+            height: line_height,
+        }
+    }
+
+    /// Returns the margin needed around the plotting area to fit the legend outside of it.
+    pub(crate) fn reserved(&self, size: (i32, i32)) -> (u32, u32) {
+        match self.position {
+            LegendPosition::OutsideRight => ((size.0 + Self::GAP) as u32, 0),
+            LegendPosition::OutsideBottom => (0, (size.1 + Self::GAP) as u32),
+            _ => (0, 0),
+        }
+    }
+
+    /// Returns the plotters position of the legend, relative to the plotting area.
+    pub(crate) fn series_label_position(
+        &self,
+        size: (i32, i32),
+        plotting_area: (Range<i32>, Range<i32>),
+        bounds: iced::Size,
+        margin: Padding,
+    ) -> SeriesLabelPosition {
+        let (x_pixels, y_pixels) = plotting_area;
+
+        match self.position {
+            LegendPosition::UpperLeft => SeriesLabelPosition::UpperLeft,
+            LegendPosition::UpperRight => SeriesLabelPosition::UpperRight,
+            LegendPosition::LowerLeft => SeriesLabelPosition::LowerLeft,
+            LegendPosition::LowerRight => SeriesLabelPosition::LowerRight,
+            LegendPosition::OutsideRight => SeriesLabelPosition::Coordinate(
+                (bounds.width - margin.right) as i32 - size.0 - x_pixels.start,
+                0,
+            ),
+            LegendPosition::OutsideBottom => SeriesLabelPosition::Coordinate(
+                0,
+                (bounds.height - margin.bottom) as i32 - size.1 - y_pixels.start,
+            ),
+        }
+    }
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self::new(LegendPosition::default())
+    }
+}
+
+impl From<LegendPosition> for Legend {
+    fn from(position: LegendPosition) -> Self {
+        Self::new(position)
+    }
+}
+
+/// The hit regions of the legend entries, recorded while drawing.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Entries(Rc<RefCell<Vec<(usize, Rectangle)>>>);
+
+impl Entries {
+    pub(crate) fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+
+    pub(crate) fn push(&self, index: usize, bounds: Rectangle) {
+        self.0.borrow_mut().push((index, bounds));
+    }
+
+    /// Returns the index of the series whose entry contains `position`.
+    pub(crate) fn find(&self, position: Point) -> Option<usize> {
+        self.0
+            .borrow()
+            .iter()
+            .find(|(_, bounds)| bounds.contains(position))
+            .map(|(index, _)| *index)
+    }
+}
@@ -63,6 +63,13 @@ where
     ) -> mouse::Interaction {
         mouse::Interaction::default()
     }
+
+    /// Returns a value which changes whenever the data drawn by the [`Program`] changes.
+    ///
+    /// The cache of a [`Chart`](crate::Chart) is cleared when it differs from the last draw.
+    fn data_version(&self) -> u64 {
+        0
+    }
 }
 
 impl<Message, Theme, Renderer, T> Program<Message, Theme, Renderer> for &T
@@ -101,4 +108,8 @@ where
     ) -> mouse::Interaction {
         T::mouse_interaction(self, state, bounds, cursor)
     }
+
+    fn data_version(&self) -> u64 {
+        T::data_version(self)
+    }
 }
@@ -0,0 +1,127 @@
+use super::Scale;
+use super::scale::{Extent, LABELS};
+
+use std::ops::Range;
+
+/// How an automatic range is fitted to the data of its axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoRange {
+    /// The space added to both ends, as a share of the data span.
+    pub padding: f64,
+    /// Whether the ends are rounded outwards to tick values, or whole decades on a log scale.
+    ///
+    /// Off by default, so the range covers the data exactly.
+    pub nice: bool,
+    /// Whether the range is extended to contain zero.
+    pub include_zero: bool,
+}
+
+impl Default for AutoRange {
+    fn default() -> Self {
+        Self {
+            padding: 0.0,
+            nice: false,
+            include_zero: false,
+        }
+    }
+}
+
+impl AutoRange {
+    /// The half span around data consisting of a single time, in seconds.
+    const TIME_SPAN: f64 = 3600.0;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn padding(mut self, padding: f64) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn nice(mut self, nice: bool) -> Self {
+        self.nice = nice;
+        self
+    }
+
+    pub fn include_zero(mut self, include_zero: bool) -> Self {
+        self.include_zero = include_zero;
+        self
+    }
+
+    /// Returns the range fitted to `values` on an axis with `scale`.
+    ///
+    /// Values which can not be drawn with the scale, like NaN, are skipped. Returns `None` if
+    /// no value is left.
+    pub fn fit(&self, values: impl IntoIterator<Item = f64>, scale: Scale) -> Option<Range<f64>> {
+        values
+            .into_iter()
+            .filter(|value| scale.contains(*value))
+            .fold(Extent::EMPTY, |extent, value| extent.include(value..value))
+            .range(&scale)
+            .map(|range| self.apply(range, scale))
+    }
+
+    /// Fits a range covering the data exactly.
+    pub(crate) fn apply(&self, range: Range<f64>, scale: Scale) -> Range<f64> {
+        let (mut start, mut end) = (range.start.min(range.end), range.start.max(range.end));
+
+        if self.include_zero && scale.contains(0.0) {
+            start = start.min(0.0);
+            end = end.max(0.0);
+        }
+
+        if start == end {
+            (start, end) = widen(start, scale);
+        }
+
+        let (mut low, mut high) = (scale.transform(start), scale.transform(end));
+        let padding = (high - low) * self.padding.max(0.0);
+
+        // Padding never pushes the range across zero
+        if !(self.include_zero && start == 0.0) {
+            low -= padding;
+        }
+
+        if !(self.include_zero && end == 0.0) {
+            high += padding;
+        }
+
+        if self.nice {
+            (low, high) = match scale {
+                Scale::Linear => {
+                    let step = nice_step(high - low, LABELS - 1);
+
+                    ((low / step).floor() * step, (high / step).ceil() * step)
+                }
+                Scale::Log(_) => (low.floor(), high.ceil()),
+                Scale::Symlog(_) | Scale::Time => (low, high),
+            };
+        }
+
+        scale.inverse(low)..scale.inverse(high)
+    }
+}
+
+/// Returns a span around `value` for data without any extent.
+fn widen(value: f64, scale: Scale) -> (f64, f64) {
+    match scale {
+        Scale::Log(base) => (value / base, value * base),
+        Scale::Time => (value - AutoRange::TIME_SPAN, value + AutoRange::TIME_SPAN),
+        Scale::Linear | Scale::Symlog(_) if value == 0.0 => (-1.0, 1.0),
+        Scale::Linear | Scale::Symlog(_) => (value - value.abs() * 0.1, value + value.abs() * 0.1),
+    }
+}
+
+/// Returns the smallest step of 1, 2 or 5 times a power of ten, dividing `span` into at most
+/// `count` steps.
+fn nice_step(span: f64, count: usize) -> f64 {
+    let raw = span / count.max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|multiple| multiple * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap_or(10.0 * magnitude)
+}
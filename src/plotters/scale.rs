@@ -0,0 +1,503 @@
+use plotters::coord::ranged1d::{
+    BoldPoints, IntoSegmentedCoord, KeyPointHint, NoDefaultFormatting, Ranged, ReversibleRanged,
+    SegmentValue, ValueFormatter,
+};
+use plotters::coord::types::RangedCoordf64;
+
+use super::axis::{AxisOptions, Ticks};
+use super::time::Step;
+
+use std::ops::Range;
+use std::sync::Arc;
+
+/// The number of tick labels plotters draws on an axis by default.
+pub(crate) const LABELS: usize = 11;
+
+/// How the values of an axis are mapped onto the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Scale {
+    #[default]
+    Linear,
+    /// Logarithmic with the given base, non-positive values are not drawn.
+    ///
+    /// The base has to be finite, positive and other than 1, see [`Scale::log`].
+    Log(f64),
+    /// Linear within the given threshold around zero and logarithmic beyond it.
+    ///
+    /// The threshold has to be finite and positive.
+    Symlog(f64),
+    /// Linear with calendar-aware ticks, values are seconds since the Unix epoch in UTC.
+    Time,
+}
+
+impl Scale {
+    pub const LOG10: Self = Self::Log(10.0);
+
+    /// Creates a logarithmic scale with the given base.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base is not finite and positive, or if it is 1.
+    pub fn log(base: f64) -> Self {
+        Self::Log(base).validated()
+    }
+
+    /// Panics if the scale cannot map values onto the screen.
+    pub(crate) fn validated(self) -> Self {
+        match self {
+            Scale::Log(base) => assert!(
+                base.is_finite() && base > 0.0 && base != 1.0,
+                "the base of a logarithmic scale has to be finite, positive and other than 1, \
+                 got {base}"
+            ),
+            Scale::Symlog(threshold) => assert!(
+                threshold.is_finite() && threshold > 0.0,
+                "the threshold of a symmetric logarithmic scale has to be finite and positive, \
+                 got {threshold}"
+            ),
+            Scale::Linear | Scale::Time => {}
+        }
+
+        self
+    }
+
+    /// Returns whether `value` can be drawn with the scale.
+    pub(crate) fn contains(&self, value: f64) -> bool {
+        match self {
+            Scale::Log(_) => value > 0.0,
+            Scale::Linear | Scale::Symlog(_) | Scale::Time => value.is_finite(),
+        }
+    }
+
+    /// Returns the range used for an axis without any data.
+    pub(crate) fn default_range(&self, linear: Range<f64>) -> Range<f64> {
+        match self {
+            Scale::Log(_) => 1.0..10.0,
+            Scale::Linear | Scale::Symlog(_) | Scale::Time => linear,
+        }
+    }
+
+    pub(crate) fn transform(&self, value: f64) -> f64 {
+        match *self {
+            Scale::Linear | Scale::Time => value,
+            Scale::Log(base) => value.log(base),
+            Scale::Symlog(threshold) => value.signum() * (value.abs() / threshold).ln_1p(),
+        }
+    }
+
+    pub(crate) fn inverse(&self, value: f64) -> f64 {
+        match *self {
+            Scale::Linear | Scale::Time => value,
+            Scale::Log(base) => base.powf(value),
+            Scale::Symlog(threshold) => value.signum() * threshold * value.abs().exp_m1(),
+        }
+    }
+}
+
+/// The extent of a series along one axis, tracking its positive part for log scales.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extent {
+    all: Range<f64>,
+    positive: Range<f64>,
+}
+
+impl Extent {
+    pub const EMPTY: Self = Self {
+        all: f64::INFINITY..f64::NEG_INFINITY,
+        positive: f64::INFINITY..f64::NEG_INFINITY,
+    };
+
+    /// Adds the range covered by a single value, ignoring NaN and infinite values.
+    pub fn include(mut self, range: Range<f64>) -> Self {
+        if !range.start.is_finite() || !range.end.is_finite() {
+            return self;
+        }
+
+        self.all = self.all.start.min(range.start)..self.all.end.max(range.end);
+
+        let start = if range.start > 0.0 {
+            range.start
+        } else {
+            range.end
+        };
+
+        if start > 0.0 {
+            self.positive = self.positive.start.min(start)..self.positive.end.max(range.end);
+        }
+
+        self
+    }
+
+    /// Moves the start down by `below` and the end up by `above`, keeping the positive part
+    /// for log scales.
+    pub(crate) fn widened(&self, below: f64, above: f64) -> Self {
+        Self::EMPTY
+            .include(self.all.start - below..self.all.end + above)
+            .include(self.positive.start..self.positive.end + above)
+    }
+
+    pub fn union(self, other: &Self) -> Self {
+        self.include(other.all.clone())
+            .include(other.positive.clone())
+    }
+
+    /// Returns the covered range drawable with `scale`, or `None` if there is none.
+    pub fn range(&self, scale: &Scale) -> Option<Range<f64>> {
+        let range = match scale {
+            Scale::Log(_) => &self.positive,
+            Scale::Linear | Scale::Symlog(_) | Scale::Time => &self.all,
+        };
+
+        (range.start <= range.end).then(|| range.clone())
+    }
+}
+
+/// A plotters coordinate mapping values with a [`Scale`].
+///
+/// With categories, the axis is linear and the category `i` covers the slot `i..i + 1`.
+#[derive(Debug, Clone)]
+pub(crate) struct ScaledRange {
+    range: Range<f64>,
+    scale: Scale,
+    categories: Arc<[String]>,
+    ticks: Ticks,
+}
+
+impl ScaledRange {
+    const MAX_TICKS: f64 = 1000.0;
+
+    pub(crate) fn new(range: Range<f64>, scale: Scale) -> Self {
+        let range = match scale {
+            // A log axis can not start at zero, fall back to a few decades below its end
+            Scale::Log(base) if range.start <= 0.0 => {
+                let end = if range.end > 0.0 { range.end } else { 1.0 };
+
+                end / base.powi(3)..end
+            }
+            _ => range,
+        };
+
+        Self {
+            range,
+            scale,
+            categories: Arc::from([]),
+            ticks: Ticks::Auto,
+        }
+    }
+
+    pub(crate) fn categorical(range: Range<f64>, categories: Arc<[String]>) -> Self {
+        Self {
+            range,
+            scale: Scale::Linear,
+            categories,
+            ticks: Ticks::Auto,
+        }
+    }
+
+    /// Places the ticks at fixed positions instead of the ones chosen by the scale.
+    pub(crate) fn with_ticks(mut self, ticks: Ticks) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Returns the category whose slot contains `value`.
+    pub(crate) fn category(&self, value: f64) -> Option<&str> {
+        if value < 0.0 {
+            return None;
+        }
+
+        self.categories.get(value as usize).map(String::as_str)
+    }
+
+    /// Returns the step between the ticks of a time axis, which sets the precision of labels.
+    fn time_step(&self) -> Step {
+        match &self.ticks {
+            Ticks::Count(count) => Step::fitting(self.span(), *count),
+            Ticks::Step(step) if *step > 0.0 && self.span() / step <= Self::MAX_TICKS => {
+                Step::Seconds(*step)
+            }
+            Ticks::Values(values) => {
+                let mut values: Vec<f64> = values
+                    .iter()
+                    .copied()
+                    .filter(|value| self.contains(*value))
+                    .collect();
+                values.sort_by(f64::total_cmp);
+
+                values
+                    .windows(2)
+                    .map(|pair| pair[1] - pair[0])
+                    .filter(|gap| *gap > 0.0)
+                    .min_by(f64::total_cmp)
+                    .map_or_else(|| Step::fitting(self.span(), LABELS), Step::Seconds)
+            }
+            Ticks::Auto | Ticks::Step(_) => Step::fitting(self.span(), LABELS),
+        }
+    }
+
+    /// Returns the major ticks and their labels as drawn on an axis with `options`.
+    pub(crate) fn labels(&self, options: &AxisOptions) -> Vec<(f64, String)> {
+        self.key_points(BoldPoints(options.tick_count_hint(LABELS)))
+            .into_iter()
+            .map(|value| (value, options.label(value, |value| self.format_ext(&value))))
+            .collect()
+    }
+
+    fn span(&self) -> f64 {
+        (self.range.end - self.range.start).abs()
+    }
+
+    fn transformed(&self) -> (f64, f64) {
+        (
+            self.scale.transform(self.range.start),
+            self.scale.transform(self.range.end),
+        )
+    }
+
+    fn log_key_points(&self, base: f64, hint: &impl KeyPointHint) -> Vec<f64> {
+        let (start, end) = self.transformed();
+        let (low, high) = (start.min(end).floor(), start.max(end).ceil());
+        let max_points = hint.max_num_points();
+
+        if max_points == 0 {
+            return Vec::new();
+        }
+
+        // Invalid bases give NaN exponents, bases close to 1 ones beyond i32
+        if !(low >= f64::from(i32::MIN) && high <= f64::from(i32::MAX)) {
+            return RangedCoordf64::from(self.range.clone()).key_points(max_points);
+        }
+
+        let (low, high) = (low as i32, high as i32);
+        let decades = (i64::from(high) - i64::from(low) + 1) as usize;
+        let step = decades.div_ceil(max_points).max(1);
+
+        // Only base 10 has well known intermediate ticks
+        let multiples: &[f64] = if base != 10.0 || decades > max_points {
+            &[1.0]
+        } else if hint.weight().allow_light_points() {
+            &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+        } else if decades * 3 <= max_points {
+            &[1.0, 2.0, 5.0]
+        } else {
+            &[1.0]
+        };
+
+        let points: Vec<f64> = (low..=high)
+            .step_by(step)
+            .flat_map(|exponent| {
+                let decade = base.powi(exponent);
+
+                multiples.iter().map(move |multiple| multiple * decade)
+            })
+            .filter(|value| self.contains(*value))
+            .collect();
+
+        // Too few ticks when the range does not span a decade
+        if points.len() < 3 {
+            return RangedCoordf64::from(self.range.clone()).key_points(max_points);
+        }
+
+        points
+    }
+
+    /// Returns the fixed ticks within the range, or `None` if the scale chooses them.
+    fn fixed_key_points(&self, hint: &impl KeyPointHint) -> Option<Vec<f64>> {
+        let (start, end) = (
+            self.range.start.min(self.range.end),
+            self.range.start.max(self.range.end),
+        );
+
+        let mut ticks: Vec<f64> = match &self.ticks {
+            Ticks::Auto | Ticks::Count(_) => return None,
+            // Too many ticks to draw, leave it to the scale
+            Ticks::Step(step) if !(*step > 0.0 && (end - start) / step <= Self::MAX_TICKS) => {
+                return None;
+            }
+            Ticks::Step(step) => {
+                let first = (start / step).ceil() as i64;
+                let last = (end / step).floor() as i64;
+
+                (first..=last).map(|i| i as f64 * step).collect()
+            }
+            Ticks::Values(values) => values
+                .iter()
+                .copied()
+                .filter(|value| self.contains(*value))
+                .collect(),
+        };
+        ticks.sort_by(f64::total_cmp);
+
+        if !hint.weight().allow_light_points() {
+            return Some(ticks);
+        }
+
+        // Minor ticks evenly divide the space between two ticks
+        let divisions = hint.max_num_points() / hint.bold_points().max(1);
+
+        if divisions == 0 {
+            return Some(Vec::new());
+        }
+
+        Some(
+            ticks
+                .windows(2)
+                .flat_map(|pair| {
+                    (1..divisions)
+                        .map(move |i| pair[0] + (pair[1] - pair[0]) * i as f64 / divisions as f64)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the centers of the visible category slots.
+    fn category_key_points(&self, hint: &impl KeyPointHint) -> Vec<f64> {
+        let (start, end) = (
+            self.range.start.min(self.range.end),
+            self.range.start.max(self.range.end),
+        );
+        let first = start.max(0.0).floor() as usize;
+        let last = (end.ceil() as usize).min(self.categories.len());
+
+        // Slots have no meaningful subdivisions
+        if first >= last || hint.weight().allow_light_points() {
+            return Vec::new();
+        }
+
+        (first..last - 1)
+            .into_segmented()
+            .key_points(hint.max_num_points())
+            .into_iter()
+            .filter_map(|value| match value {
+                SegmentValue::CenterOf(index) => Some(index as f64 + 0.5),
+                SegmentValue::Exact(_) | SegmentValue::Last => None,
+            })
+            .filter(|value| self.contains(*value))
+            .collect()
+    }
+
+    fn symlog_key_points(&self, threshold: f64, hint: &impl KeyPointHint) -> Vec<f64> {
+        let max_points = hint.max_num_points();
+        let magnitude = self.range.start.abs().max(self.range.end.abs());
+
+        if max_points == 0 || magnitude <= threshold {
+            return RangedCoordf64::from(self.range.clone()).key_points(max_points);
+        }
+
+        let low = threshold.log10().ceil() as i32;
+        let high = magnitude.log10().ceil() as i32;
+        let signs = if self.range.start < 0.0 && self.range.end > 0.0 {
+            2
+        } else {
+            1
+        };
+        let ticks = (high - low + 1) as usize * signs + 1;
+        let step = ticks.div_ceil(max_points).max(1);
+
+        let mut points: Vec<f64> = (low..=high)
+            .step_by(step)
+            .flat_map(|exponent| {
+                let decade = 10f64.powi(exponent);
+
+                [-decade, decade]
+            })
+            .chain([0.0])
+            .filter(|value| self.contains(*value))
+            .collect();
+
+        points.sort_by(f64::total_cmp);
+        points
+    }
+
+    fn contains(&self, value: f64) -> bool {
+        let (low, high) = if self.range.start <= self.range.end {
+            (self.range.start, self.range.end)
+        } else {
+            (self.range.end, self.range.start)
+        };
+
+        (low..=high).contains(&value)
+    }
+}
+
+impl Ranged for ScaledRange {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        if let Scale::Linear | Scale::Time = self.scale {
+            return RangedCoordf64::from(self.range.clone()).map(value, limit);
+        }
+
+        let (start, end) = self.transformed();
+
+        if start == end {
+            return (limit.1 - limit.0) / 2;
+        }
+
+        let value = self.scale.transform(*value);
+        let value = if value.is_nan() {
+            f64::NEG_INFINITY
+        } else {
+            value
+        };
+        let logic_length = (value - start) / (end - start);
+        let actual_length = f64::from(limit.1 - limit.0);
+
+        // Values outside of the scale end up far away from the plotting area
+        let offset = (actual_length * logic_length).clamp(-1e6, 1e6);
+
+        limit.0 + offset.round() as i32
+    }
+
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        if let Some(ticks) = self.fixed_key_points(&hint) {
+            return ticks;
+        }
+
+        match self.scale {
+            Scale::Linear if !self.categories.is_empty() => self.category_key_points(&hint),
+            Scale::Linear => RangedCoordf64::from(self.range.clone()).key_points(hint),
+            Scale::Log(base) => self.log_key_points(base, &hint),
+            Scale::Symlog(threshold) => self.symlog_key_points(threshold, &hint),
+            Scale::Time => Step::fitting(self.span(), hint.max_num_points()).ticks(self.range()),
+        }
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+}
+
+impl ReversibleRanged for ScaledRange {
+    fn unmap(&self, input: i32, limit: (i32, i32)) -> Option<f64> {
+        if input < limit.0.min(limit.1) || input > limit.0.max(limit.1) || limit.0 == limit.1 {
+            return None;
+        }
+
+        let (start, end) = self.transformed();
+        let offset = f64::from(input - limit.0) / f64::from(limit.1 - limit.0);
+
+        Some(self.scale.inverse(start + (end - start) * offset))
+    }
+}
+
+impl ValueFormatter<f64> for ScaledRange {
+    fn format_ext(&self, value: &f64) -> String {
+        if !self.categories.is_empty() {
+            return self.category(*value).unwrap_or_default().to_string();
+        }
+
+        if self.scale == Scale::Time {
+            return self.time_step().format(*value);
+        }
+
+        let magnitude = value.abs();
+
+        if self.scale != Scale::Linear && magnitude != 0.0 && !(1e-4..1e4).contains(&magnitude) {
+            format!("{value:e}")
+        } else {
+            RangedCoordf64::format(value)
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Downsample, Extent};
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// Points a series queries by x range, like a memory mapped file or a columnar store.
+///
+/// The points are expected in order of x, with NaN values breaking a line. [`Data`] keeps them
+/// in memory.
+///
+/// [`Data`]: crate::Data
+pub trait DataSource {
+    /// Returns the x and y extent of the points.
+    fn bounds(&self) -> (Extent, Extent);
+
+    /// Returns the points within `x_range`, plus the closest point on each side of it so a line
+    /// reaches the border.
+    fn points(&self, x_range: Range<f64>) -> Cow<'_, [(f64, f64)]>;
+
+    /// Returns the y extent automatic y ranges are fitted to while the x axis shows the given
+    /// range.
+    ///
+    /// By default, it is the y extent of all points from [`bounds`](Self::bounds).
+    fn y_bounds(&self, _x_range: Range<f64>) -> Extent {
+        self.bounds().1
+    }
+
+    /// Returns the visible points like [`points`](Self::points), along with the index of the
+    /// first of them among all points, which matches them with their error bars.
+    ///
+    /// By default, all points are returned from index `0`.
+    fn indexed_points(&self, _x_range: Range<f64>) -> (usize, Cow<'_, [(f64, f64)]>) {
+        (0, self.points(f64::NEG_INFINITY..f64::INFINITY))
+    }
+
+    /// Returns the points within `x_range` reduced for a line `columns` pixels wide.
+    ///
+    /// By default, the result of [`points`](Self::points) is reduced with `downsample`, keeping
+    /// the breaks at NaN values.
+    fn downsampled(
+        &self,
+        x_range: Range<f64>,
+        columns: usize,
+        downsample: Downsample,
+    ) -> Cow<'_, [(f64, f64)]> {
+        let points = self.points(x_range.clone());
+
+        match downsample.apply(&points, x_range, columns) {
+            Some(reduced) => Cow::Owned(reduced),
+            None => points,
+        }
+    }
+
+    /// Returns a value unique to the current points, or `0` if they are not tracked.
+    ///
+    /// A cached [`Chart`](crate::Chart) is redrawn when it changes.
+    fn version(&self) -> u64 {
+        0
+    }
+}
@@ -0,0 +1,262 @@
+use super::scale::Extent;
+use super::{Axis, AxisValue, Color, Data, DataSource, LineSeries, Scale, Series, cull};
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::ops::Range;
+
+/// A line of live samples, following the newest of them in a sliding x window.
+///
+/// Samples are pushed in order of x. The series keeps the newest `capacity` of them, dropping
+/// older ones in batches so that pushing stays cheap. Converting it into a [`Series`] does not
+/// copy the samples.
+#[derive(Clone)]
+pub struct StreamingSeries {
+    data: Data,
+    capacity: usize,
+    window: f64,
+    /// The end of the window while it does not follow the newest sample.
+    paused: Option<f64>,
+    /// The samples of the followed window which may still become its lowest or highest one.
+    lowest: VecDeque<(f64, f64)>,
+    highest: VecDeque<(f64, f64)>,
+    /// The color of the series, or `None` to pick one from the palette of the chart.
+    pub color: Option<Color>,
+    pub label: Option<String>,
+    pub visible: bool,
+    pub axis: Axis,
+}
+
+impl StreamingSeries {
+    /// Creates a series keeping `capacity` samples and showing the last `window` of them on the
+    /// x axis.
+    pub fn new(capacity: usize, window: impl AxisValue) -> Self {
+        Self {
+            data: Data::from(Vec::with_capacity(capacity * 2)),
+            capacity: capacity.max(1),
+            window: window.to_f64().abs(),
+            paused: None,
+            lowest: VecDeque::new(),
+            highest: VecDeque::new(),
+            color: None,
+            label: None,
+            visible: true,
+            axis: Axis::Primary,
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the label of the series shown in the legend.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the series is drawn and included in automatic ranges.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the y axis the series is drawn against.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn push(&mut self, sample: (impl AxisValue, impl AxisValue)) {
+        self.extend(std::iter::once(sample));
+    }
+
+    pub fn extend(&mut self, iter: impl IntoIterator<Item = (impl AxisValue, impl AxisValue)>) {
+        let capacity = self.capacity;
+        let (lowest, highest) = (&mut self.lowest, &mut self.highest);
+
+        self.data.update(|points| {
+            for (x, y) in iter {
+                let sample = (x.to_f64(), y.to_f64());
+                points.push(sample);
+
+                if !sample.0.is_finite() || !sample.1.is_finite() {
+                    continue;
+                }
+
+                while lowest.back().is_some_and(|low| low.1 >= sample.1) {
+                    lowest.pop_back();
+                }
+
+                while highest.back().is_some_and(|high| high.1 <= sample.1) {
+                    highest.pop_back();
+                }
+
+                lowest.push_back(sample);
+                highest.push_back(sample);
+            }
+
+            if points.len() >= capacity * 2 {
+                points.drain(..points.len() - capacity);
+            }
+        });
+
+        // Samples which left the followed window can no longer be its extremes
+        let start = self.followed_start();
+
+        while self.lowest.front().is_some_and(|low| low.0 < start) {
+            self.lowest.pop_front();
+        }
+
+        while self.highest.front().is_some_and(|high| high.0 < start) {
+            self.highest.pop_front();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.lowest.clear();
+        self.highest.clear();
+        self.paused = None;
+    }
+
+    /// Returns the kept samples, oldest first.
+    pub fn samples(&self) -> &[(f64, f64)] {
+        &self.data[self.data.len().saturating_sub(self.capacity)..]
+    }
+
+    pub fn latest(&self) -> Option<(f64, f64)> {
+        self.data.last().copied()
+    }
+
+    /// Returns whether the window follows the newest sample.
+    pub fn is_following(&self) -> bool {
+        self.paused.is_none()
+    }
+
+    /// Moves the window along with every new sample again.
+    pub fn follow(&mut self) {
+        self.paused = None;
+    }
+
+    /// Keeps the window where it is while new samples arrive.
+    pub fn pause(&mut self) {
+        self.paused = Some(self.x_range().end);
+    }
+
+    /// Moves the window by `delta` along the x axis and stops following the newest sample.
+    ///
+    /// Following resumes once the window reaches the newest sample again.
+    pub fn pan(&mut self, delta: f64) {
+        let Some((newest, oldest)) = self.latest().zip(self.samples().first()) else {
+            return;
+        };
+        let end = self.x_range().end + delta;
+
+        self.paused = if end >= newest.0 {
+            None
+        } else {
+            Some(end.max(oldest.0))
+        };
+    }
+
+    /// Returns the x range of the window.
+    ///
+    /// Passing it to [`Chart::x_range`] slides the window smoothly, instead of rounding it to
+    /// ticks.
+    ///
+    /// [`Chart::x_range`]: crate::Chart::x_range
+    pub fn x_range(&self) -> Range<f64> {
+        let end = self
+            .paused
+            .or_else(|| self.latest().map(|latest| latest.0))
+            .unwrap_or(self.window);
+
+        end - self.window..end
+    }
+
+    /// Returns the range of the y values within the window.
+    pub fn y_range(&self) -> Option<Range<f64>> {
+        self.y_extent().range(&Scale::Linear)
+    }
+
+    /// Returns the y extent of the window, kept up to date while it follows the newest sample.
+    fn y_extent(&self) -> Extent {
+        if self.is_following() {
+            return self
+                .lowest
+                .front()
+                .into_iter()
+                .chain(self.highest.front())
+                .fold(Extent::EMPTY, |extent, &(_, y)| extent.include(y..y));
+        }
+
+        let Range { start, end } = self.x_range();
+        let samples = self.samples();
+        let first = samples.partition_point(|sample| sample.0 < start);
+        let last = samples.partition_point(|sample| sample.0 <= end);
+
+        samples[first..last.max(first)]
+            .iter()
+            .fold(Extent::EMPTY, |extent, &(_, y)| extent.include(y..y))
+    }
+
+    /// Returns the start of the followed window, or of the kept samples if that is later.
+    fn followed_start(&self) -> f64 {
+        let oldest = self
+            .samples()
+            .first()
+            .map_or(f64::NEG_INFINITY, |oldest| oldest.0);
+        let newest = self.latest().map_or(f64::NEG_INFINITY, |newest| newest.0);
+
+        oldest.max(newest - self.window)
+    }
+}
+
+impl From<&StreamingSeries> for Series {
+    fn from(series: &StreamingSeries) -> Self {
+        let x_range = series.x_range();
+        let kept = Kept {
+            data: series.data.clone(),
+            capacity: series.capacity,
+            bounds: (
+                Extent::EMPTY.include(x_range.start..x_range.end),
+                series.y_extent(),
+            ),
+        };
+
+        Series::Line(LineSeries {
+            color: series.color,
+            label: series.label.clone(),
+            visible: series.visible,
+            axis: series.axis,
+            ..LineSeries::from_source(kept)
+        })
+    }
+}
+
+/// The kept samples of a [`StreamingSeries`], sharing its buffer which may still hold older
+/// samples waiting to be dropped.
+struct Kept {
+    data: Data,
+    capacity: usize,
+    bounds: (Extent, Extent),
+}
+
+impl DataSource for Kept {
+    fn bounds(&self) -> (Extent, Extent) {
+        self.bounds.clone()
+    }
+
+    fn points(&self, x_range: Range<f64>) -> Cow<'_, [(f64, f64)]> {
+        let kept = &self.data[self.data.len().saturating_sub(self.capacity)..];
+
+        // The kept samples are sorted if the whole buffer is
+        Cow::Borrowed(cull::visible(kept, x_range, self.data.is_sorted()))
+    }
+
+    fn version(&self) -> u64 {
+        self.data.version()
+    }
+}
@@ -0,0 +1,149 @@
+//! Change the appearance of a [`Chart`].
+//!
+//! [`Chart`]: crate::Chart
+use iced::{Color, Theme};
+
+/// The possible status of a [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Chart`] is idle.
+    ///
+    /// [`Chart`]: crate::Chart
+    Active,
+    /// The [`Chart`] is being hovered.
+    ///
+    /// [`Chart`]: crate::Chart
+    Hovered,
+}
+
+/// The appearance of a [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// The background of the whole chart, if any.
+    pub background: Option<Color>,
+    /// The color of the axis lines.
+    pub axis: Color,
+    /// The color of the grid lines at the labeled ticks.
+    pub major_grid: Color,
+    /// The color of the grid lines between the labeled ticks.
+    pub minor_grid: Color,
+    /// The color of the tick labels.
+    pub label: Color,
+    /// The color of the caption and the axis descriptions.
+    pub caption: Color,
+    /// The appearance of the legend.
+    pub legend: LegendStyle,
+    /// The colors assigned in order to series without an explicit color.
+    pub palette: Vec<Color>,
+}
+
+/// The appearance of the legend of a [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegendStyle {
+    /// The background of the legend box.
+    pub background: Color,
+    /// The border color of the legend box.
+    pub border: Color,
+    /// The color of the series labels.
+    pub text: Color,
+}
+
+/// The theme catalog of a [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`Chart`].
+///
+/// [`Chart`]: crate::Chart
+pub fn default(theme: &Theme, _status: Status) -> Style {
+    let palette = theme.extended_palette();
+    let text = palette.background.base.text;
+
+    Style {
+        background: None,
+        axis: text,
+        major_grid: Color {
+            a: 0.15,
+            ..palette.primary.base.color
+        },
+        minor_grid: Color {
+            a: 0.05,
+            ..palette.primary.base.color
+        },
+        label: text,
+        caption: text,
+        legend: LegendStyle {
+            background: Color {
+                a: 0.8,
+                ..palette.background.base.color
+            },
+            border: palette.background.strong.color,
+            text,
+        },
+        palette: series_palette(theme),
+    }
+}
+
+/// Derives a palette of distinguishable series colors from the accent colors of the theme.
+pub fn series_palette(theme: &Theme) -> Vec<Color> {
+    let palette = theme.extended_palette();
+
+    let primary = palette.primary.base.color;
+    let success = palette.success.base.color;
+    let danger = palette.danger.base.color;
+
+    vec![
+        primary,
+        danger,
+        success,
+        mix(primary, danger),
+        mix(danger, success),
+        mix(success, primary),
+        palette.primary.strong.color,
+        palette.danger.strong.color,
+        palette.success.strong.color,
+        palette.secondary.base.color,
+    ]
+}
+
+fn mix(a: Color, b: Color) -> Color {
+    Color::from_rgba(
+        (a.r + b.r) / 2.0,
+        (a.g + b.g) / 2.0,
+        (a.b + b.b) / 2.0,
+        (a.a + b.a) / 2.0,
+    )
+}
@@ -0,0 +1,218 @@
+//! Calendar-aware ticks for time axes.
+//!
+//! Times are seconds since the Unix epoch and are always shown in UTC.
+use super::value::AxisValue;
+
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+const WEEK: f64 = 7.0 * DAY;
+/// The 5th of January 1970, the first Monday after the epoch.
+const FIRST_MONDAY: f64 = 4.0 * DAY;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The distance between two ticks of a time axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Step {
+    Seconds(f64),
+    Week,
+    Months(i64),
+    Years(i64),
+}
+
+impl Step {
+    const SECONDS: [f64; 28] = [
+        0.001,
+        0.002,
+        0.005,
+        0.01,
+        0.02,
+        0.05,
+        0.1,
+        0.2,
+        0.5,
+        1.0,
+        2.0,
+        5.0,
+        10.0,
+        15.0,
+        30.0,
+        MINUTE,
+        2.0 * MINUTE,
+        5.0 * MINUTE,
+        10.0 * MINUTE,
+        15.0 * MINUTE,
+        30.0 * MINUTE,
+        HOUR,
+        2.0 * HOUR,
+        3.0 * HOUR,
+        6.0 * HOUR,
+        12.0 * HOUR,
+        DAY,
+        2.0 * DAY,
+    ];
+    const MONTHS: [i64; 4] = [1, 2, 3, 6];
+
+    /// Returns the smallest step producing at most `max_points` ticks over `span` seconds.
+    pub(crate) fn fitting(span: f64, max_points: usize) -> Self {
+        let max_points = max_points.max(1) as f64;
+
+        let fixed = Self::SECONDS.iter().map(|&seconds| Step::Seconds(seconds));
+        let weekly = [Step::Week];
+        let monthly = Self::MONTHS.iter().map(|&months| Step::Months(months));
+
+        fixed
+            .chain(weekly)
+            .chain(monthly)
+            .find(|step| span / step.seconds() <= max_points)
+            .unwrap_or_else(|| {
+                let years = span / (365.25 * DAY) / max_points;
+                let magnitude = 10f64.powi(years.log10().floor() as i32);
+                let multiple = [1.0, 2.0, 5.0, 10.0]
+                    .into_iter()
+                    .find(|multiple| multiple * magnitude >= years)
+                    .unwrap_or(10.0);
+
+                Step::Years((multiple * magnitude).max(1.0) as i64)
+            })
+    }
+
+    /// The approximate length of the step in seconds.
+    fn seconds(&self) -> f64 {
+        match self {
+            Step::Seconds(seconds) => *seconds,
+            Step::Week => WEEK,
+            Step::Months(months) => *months as f64 * 30.44 * DAY,
+            Step::Years(years) => *years as f64 * 365.25 * DAY,
+        }
+    }
+
+    /// Returns the ticks within `range`.
+    pub(crate) fn ticks(&self, range: Range<f64>) -> Vec<f64> {
+        let (start, end) = (range.start.min(range.end), range.start.max(range.end));
+
+        match *self {
+            Step::Seconds(step) => multiples(start, end, 0.0, step),
+            Step::Week => multiples(start, end, FIRST_MONDAY, WEEK),
+            Step::Months(step) => {
+                let (year, month, _) = civil_from_days((start / DAY).floor() as i64);
+                let first = (year * 12 + month - 1).div_euclid(step) * step;
+
+                (0..)
+                    .map(|i| first + i * step)
+                    .map(|month| month_start(month.div_euclid(12), month.rem_euclid(12) + 1))
+                    .skip_while(|time| *time < start)
+                    .take_while(|time| *time <= end)
+                    .collect()
+            }
+            Step::Years(step) => {
+                let (year, _, _) = civil_from_days((start / DAY).floor() as i64);
+                let first = year.div_euclid(step) * step;
+
+                (0..)
+                    .map(|i| month_start(first + i * step, 1))
+                    .skip_while(|time| *time < start)
+                    .take_while(|time| *time <= end)
+                    .collect()
+            }
+        }
+    }
+
+    /// Formats a tick with the precision of the step.
+    pub(crate) fn format(&self, time: f64) -> String {
+        let milliseconds = (time * 1000.0).round() as i64;
+        let days = milliseconds.div_euclid(86_400_000);
+        let of_day = milliseconds.rem_euclid(86_400_000);
+        let (year, month, day) = civil_from_days(days);
+        let (hour, minute) = (of_day / 3_600_000, of_day / 60_000 % 60);
+        let (second, millisecond) = (of_day / 1000 % 60, of_day % 1000);
+        let month_name = MONTHS[month as usize - 1];
+
+        match *self {
+            Step::Years(_) => format!("{year}"),
+            Step::Months(_) => format!("{month_name} {year}"),
+            Step::Week => format!("{month_name} {day}"),
+            Step::Seconds(step) if step >= DAY || of_day == 0 => format!("{month_name} {day}"),
+            Step::Seconds(step) if step >= MINUTE => format!("{hour:02}:{minute:02}"),
+            Step::Seconds(step) if step >= 1.0 => {
+                format!("{hour:02}:{minute:02}:{second:02}")
+            }
+            Step::Seconds(_) => format!("{minute:02}:{second:02}.{millisecond:03}"),
+        }
+    }
+}
+
+fn multiples(start: f64, end: f64, offset: f64, step: f64) -> Vec<f64> {
+    let first = ((start - offset) / step).ceil() as i64;
+    let last = ((end - offset) / step).floor() as i64;
+
+    (first..=last).map(|i| offset + i as f64 * step).collect()
+}
+
+fn month_start(year: i64, month: i64) -> f64 {
+    days_from_civil(year, month, 1) as f64 * DAY
+}
+
+/// Converts days since the epoch into a `(year, month, day)` date of the proleptic Gregorian
+/// calendar.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+
+    (year_of_era + era * 400 + i64::from(month <= 2), month, day)
+}
+
+/// Converts a date of the proleptic Gregorian calendar into days since the epoch.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+impl AxisValue for SystemTime {
+    fn to_f64(self) -> f64 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs_f64(),
+            Err(error) => -error.duration().as_secs_f64(),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> AxisValue for chrono::DateTime<Tz> {
+    fn to_f64(self) -> f64 {
+        self.timestamp() as f64 + f64::from(self.timestamp_subsec_nanos()) / 1e9
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl AxisValue for chrono::NaiveDateTime {
+    fn to_f64(self) -> f64 {
+        self.and_utc().to_f64()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl AxisValue for chrono::NaiveDate {
+    fn to_f64(self) -> f64 {
+        self.and_time(chrono::NaiveTime::MIN).to_f64()
+    }
+}
@@ -0,0 +1,42 @@
+/// A value which can be placed on an axis of a [`Chart`].
+///
+/// Times, like [`SystemTime`](std::time::SystemTime), are placed at their seconds since the
+/// Unix epoch and are meant to be used with [`Scale::Time`](crate::Scale::Time).
+///
+/// [`Chart`]: crate::Chart
+pub trait AxisValue {
+    fn to_f64(self) -> f64;
+}
+
+impl AxisValue for f32 {
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+}
+
+impl AxisValue for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+/// Missing values are NaN, which are not drawn and break [`LineSeries`](crate::LineSeries).
+impl<T: AxisValue> AxisValue for Option<T> {
+    fn to_f64(self) -> f64 {
+        self.map_or(f64::NAN, AxisValue::to_f64)
+    }
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {
+        $(
+            impl AxisValue for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
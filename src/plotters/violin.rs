@@ -0,0 +1,199 @@
+use super::boxplot::{self, BoxSummary, Whiskers};
+use super::scale::Extent;
+use super::{Axis, AxisValue, Color};
+
+use std::collections::HashMap;
+
+/// Samples per category, drawn as a kernel density estimate mirrored around the center of
+/// the category with a line at the median.
+///
+/// The widest part of every violin covers its share of the category slot.
+#[derive(Clone)]
+pub struct ViolinSeries {
+    pub data: Vec<(String, Vec<f64>)>,
+    /// The bandwidth of the Gaussian kernel, or `None` to follow Silverman's rule of thumb.
+    pub bandwidth: Option<f64>,
+    /// The color of the series, or `None` to pick one from the palette of the chart.
+    pub color: Option<Color>,
+    pub label: Option<String>,
+    pub visible: bool,
+    pub axis: Axis,
+    /// The share of a category slot covered by the violins of all series, between 0 and 1.
+    pub width: f64,
+}
+
+impl ViolinSeries {
+    /// The number of points the density is evaluated at.
+    const RESOLUTION: usize = 100;
+
+    pub fn new(
+        iter: impl IntoIterator<Item = (impl Into<String>, impl IntoIterator<Item = impl AxisValue>)>,
+    ) -> Self {
+        Self {
+            data: boxplot::group(iter),
+            bandwidth: None,
+            color: None,
+            label: None,
+            visible: true,
+            axis: Axis::Primary,
+            width: 0.8,
+        }
+    }
+
+    pub fn bandwidth(mut self, bandwidth: f64) -> Self {
+        self.bandwidth = Some(bandwidth);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the label of the series shown in the legend.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets whether the series is drawn and included in automatic ranges.
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Sets the y axis the series is drawn against.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Returns the summary of the samples of `category`, like for a tooltip of the violin
+    /// under the cursor.
+    pub fn summary(&self, category: &str) -> Option<BoxSummary> {
+        BoxSummary::new(self.samples(category)?, Whiskers::Tukey)
+    }
+
+    /// Returns the estimated density of the samples of `category` at evenly spaced values
+    /// from the smallest to the largest sample.
+    pub fn density(&self, category: &str) -> Option<Vec<(f64, f64)>> {
+        density(self.samples(category)?, self.bandwidth)
+    }
+
+    fn samples(&self, category: &str) -> Option<&[f64]> {
+        self.data
+            .iter()
+            .find(|(other, _)| other == category)
+            .map(|(_, samples)| samples.as_slice())
+    }
+
+    pub(crate) fn categories(&self) -> impl Iterator<Item = &str> {
+        self.data.iter().map(|(category, _)| category.as_str())
+    }
+
+    pub(crate) fn y_bounds(&self) -> Extent {
+        boxplot::y_bounds(&self.data)
+    }
+
+    /// Iterates over the outline and the median line of every violin, being the
+    /// `position`th of `count` shapes per slot.
+    pub(crate) fn violins<'a>(
+        &'a self,
+        slots: &'a HashMap<&str, usize>,
+        group: (usize, usize),
+    ) -> impl Iterator<Item = Violin> + 'a {
+        self.data.iter().filter_map(move |(category, samples)| {
+            let (center, half_width) = boxplot::placement(slots, category, group, self.width)?;
+            let density = density(samples, self.bandwidth)?;
+            let peak = density.iter().fold(0.0, |peak: f64, (_, d)| peak.max(*d));
+            let scale = if peak > 0.0 { half_width / peak } else { 0.0 };
+
+            let right = density.iter().map(|&(y, d)| (center + d * scale, y));
+            let left = density.iter().rev().map(|&(y, d)| (center - d * scale, y));
+
+            let summary = BoxSummary::new(samples, Whiskers::Tukey)?;
+            let (_, reach) = density.iter().min_by(|a, b| {
+                (a.0 - summary.median)
+                    .abs()
+                    .total_cmp(&(b.0 - summary.median).abs())
+            })?;
+            let reach = reach * scale;
+
+            Some(Violin {
+                outline: right.chain(left).collect(),
+                median: [
+                    (center - reach, summary.median),
+                    (center + reach, summary.median),
+                ],
+            })
+        })
+    }
+}
+
+/// The shape of a violin in chart coordinates.
+pub(crate) struct Violin {
+    pub outline: Vec<(f64, f64)>,
+    pub median: [(f64, f64); 2],
+}
+
+/// Estimates the density of the finite `samples` with a Gaussian kernel.
+fn density(samples: &[f64], bandwidth: Option<f64>) -> Option<Vec<(f64, f64)>> {
+    let mut sorted: Vec<f64> = samples
+        .iter()
+        .copied()
+        .filter(|sample| sample.is_finite())
+        .collect();
+    sorted.sort_by(f64::total_cmp);
+
+    let (&min, &max) = (sorted.first()?, sorted.last()?);
+    let bandwidth = bandwidth
+        .filter(|bandwidth| *bandwidth > 0.0)
+        .unwrap_or_else(|| silverman(&sorted));
+    let n = sorted.len() as f64;
+    let norm = 1.0 / (n * bandwidth * (2.0 * std::f64::consts::PI).sqrt());
+
+    Some(
+        (0..ViolinSeries::RESOLUTION)
+            .map(|i| {
+                let y = min + (max - min) * i as f64 / (ViolinSeries::RESOLUTION - 1) as f64;
+                let density = sorted
+                    .iter()
+                    .map(|sample| (-0.5 * ((y - sample) / bandwidth).powi(2)).exp())
+                    .sum::<f64>();
+
+                (y, density * norm)
+            })
+            .collect(),
+    )
+}
+
+/// Returns the bandwidth of Silverman's rule of thumb for `sorted` samples, falling back to
+/// `1` when they do not spread.
+fn silverman(sorted: &[f64]) -> f64 {
+    let n = sorted.len() as f64;
+    let mean = sorted.iter().sum::<f64>() / n;
+    let deviation = (sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+    let iqr = boxplot::percentile(sorted, 0.75) - boxplot::percentile(sorted, 0.25);
+    let spread = if iqr > 0.0 {
+        deviation.min(iqr / 1.34)
+    } else {
+        deviation
+    };
+
+    if spread > 0.0 {
+        0.9 * spread * n.powf(-0.2)
+    } else {
+        1.0
+    }
+}
+
+pub fn violin_series(
+    iter: impl IntoIterator<Item = (impl Into<String>, impl IntoIterator<Item = impl AxisValue>)>,
+) -> ViolinSeries {
+    ViolinSeries::new(iter)
+}
@@ -0,0 +1,155 @@
+use pliced::{AutoRange, Scale};
+
+use std::ops::Range;
+
+fn assert_range(actual: Option<Range<f64>>, expected: Range<f64>) {
+    let actual = actual.expect("a range");
+
+    assert!(
+        (actual.start - expected.start).abs() < 1e-9 && (actual.end - expected.end).abs() < 1e-9,
+        "expected {expected:?}, got {actual:?}"
+    );
+}
+
+#[test]
+fn exact_range_by_default() {
+    let range = AutoRange::new().fit([1.3, 4.2, 2.0], Scale::Linear);
+
+    assert_range(range, 1.3..4.2);
+}
+
+#[test]
+fn nice_bounds_round_outwards() {
+    assert_range(
+        AutoRange::new().nice(true).fit([0.3, 9.7], Scale::Linear),
+        0.0..10.0,
+    );
+    assert_range(
+        AutoRange::new().nice(true).fit([0.0, 25.0], Scale::Linear),
+        0.0..25.0,
+    );
+    assert_range(
+        AutoRange::new()
+            .nice(true)
+            .fit([-13.0, 87.0], Scale::Linear),
+        -20.0..90.0,
+    );
+}
+
+#[test]
+fn nice_bounds_keep_large_offsets() {
+    let range = AutoRange::new()
+        .nice(true)
+        .fit([1_700_000_000.25, 1_700_000_000.75], Scale::Linear)
+        .unwrap();
+
+    assert!(range.start <= 1_700_000_000.25 && range.start > 1_700_000_000.0);
+    assert!(range.end >= 1_700_000_000.75 && range.end < 1_700_000_001.0);
+}
+
+#[test]
+fn padding_extends_both_ends() {
+    let range = AutoRange::new()
+        .padding(0.1)
+        .fit([0.0, 10.0], Scale::Linear);
+
+    assert_range(range, -1.0..11.0);
+}
+
+#[test]
+fn include_zero() {
+    let auto_range = AutoRange::new().include_zero(true);
+
+    assert_range(auto_range.fit([3.0, 5.0], Scale::Linear), 0.0..5.0);
+    assert_range(auto_range.fit([-5.0, -3.0], Scale::Linear), -5.0..0.0);
+    assert_range(auto_range.fit([-2.0, 3.0], Scale::Linear), -2.0..3.0);
+}
+
+#[test]
+fn padding_does_not_cross_included_zero() {
+    let range = AutoRange::new()
+        .include_zero(true)
+        .padding(0.1)
+        .fit([2.0, 10.0], Scale::Linear);
+
+    assert_range(range, 0.0..11.0);
+}
+
+#[test]
+fn include_zero_is_ignored_on_log_scales() {
+    let range = AutoRange::new()
+        .include_zero(true)
+        .fit([10.0, 100.0], Scale::LOG10);
+
+    assert_range(range, 10.0..100.0);
+}
+
+#[test]
+fn nan_and_infinite_values_are_skipped() {
+    let range = AutoRange::new().fit(
+        [f64::NAN, 2.0, f64::INFINITY, 4.0, f64::NEG_INFINITY],
+        Scale::Linear,
+    );
+
+    assert_range(range, 2.0..4.0);
+}
+
+#[test]
+fn empty_data_has_no_range() {
+    assert_eq!(AutoRange::new().fit([], Scale::Linear), None);
+    assert_eq!(AutoRange::new().fit([f64::NAN], Scale::Linear), None);
+    assert_eq!(AutoRange::new().fit([-1.0, 0.0], Scale::LOG10), None);
+}
+
+#[test]
+fn single_value_is_widened() {
+    let auto_range = AutoRange::new();
+
+    assert_range(auto_range.fit([5.0], Scale::Linear), 4.5..5.5);
+    assert_range(auto_range.fit([-5.0, -5.0], Scale::Linear), -5.5..-4.5);
+    assert_range(auto_range.fit([0.0], Scale::Linear), -1.0..1.0);
+    assert_range(auto_range.fit([100.0], Scale::LOG10), 10.0..1000.0);
+    assert_range(auto_range.fit([7200.0], Scale::Time), 3600.0..10800.0);
+}
+
+#[test]
+fn single_value_with_nice_bounds() {
+    assert_range(
+        AutoRange::new().nice(true).fit([5.0], Scale::Linear),
+        4.5..5.5,
+    );
+    assert_range(
+        AutoRange::new().nice(true).fit([0.0], Scale::Linear),
+        -1.0..1.0,
+    );
+}
+
+#[test]
+fn log_scale_snaps_to_decades() {
+    let range = AutoRange::new().nice(true).fit([3.0, 450.0], Scale::LOG10);
+
+    assert_range(range, 1.0..1000.0);
+}
+
+#[test]
+fn log_scale_skips_non_positive_values() {
+    let range = AutoRange::new().fit([-3.0, 0.0, 2.0, 20.0], Scale::LOG10);
+
+    assert_range(range, 2.0..20.0);
+}
+
+#[test]
+fn log_scale_pads_in_decades() {
+    let range = AutoRange::new()
+        .padding(0.5)
+        .fit([10.0, 100.0], Scale::LOG10);
+
+    assert_range(range, 10f64.powf(0.5)..10f64.powf(2.5));
+}
+
+#[test]
+fn time_scale_is_not_rounded() {
+    let range = AutoRange::new().fit([1_700_000_123.0, 1_700_003_456.0], Scale::Time);
+
+    assert_range(range, 1_700_000_123.0..1_700_003_456.0);
+}
@@ -0,0 +1,127 @@
+use pliced::{BoxSummary, Whiskers, boxplot_series, violin_series};
+
+use plotters::data::Quartiles;
+
+fn assert_close(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance, "{a} != {b}");
+}
+
+#[test]
+fn quartiles_match_plotters() {
+    let samples = [7.0, 1.0, 3.0, 12.0, 5.0, 4.0, 9.0, 2.0];
+    let summary = BoxSummary::new(&samples, Whiskers::MinMax).unwrap();
+    let quartiles = Quartiles::new(&samples);
+    let values = quartiles.values();
+
+    assert_close(summary.lower_quartile, values[1] as f64, 1e-6);
+    assert_close(summary.median, quartiles.median(), 1e-12);
+    assert_close(summary.upper_quartile, values[3] as f64, 1e-6);
+}
+
+#[test]
+fn tukey_whiskers_reach_the_last_sample_within_the_fences() {
+    let samples = [-20.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 30.0];
+    let summary = BoxSummary::new(&samples, Whiskers::Tukey).unwrap();
+
+    // The quartiles are 2.25 and 6.75, so the fences are at -4.5 and 13.5
+    assert_eq!(summary.lower_quartile, 2.25);
+    assert_eq!(summary.upper_quartile, 6.75);
+    assert_eq!(summary.lower_whisker, 1.0);
+    assert_eq!(summary.upper_whisker, 8.0);
+    assert_eq!(summary.outliers, vec![-20.0, 30.0]);
+    assert_eq!(summary.count, 10);
+}
+
+#[test]
+fn min_max_whiskers_have_no_outliers() {
+    let samples = [-20.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 30.0];
+    let summary = BoxSummary::new(&samples, Whiskers::MinMax).unwrap();
+
+    assert_eq!(summary.lower_whisker, -20.0);
+    assert_eq!(summary.upper_whisker, 30.0);
+    assert!(summary.outliers.is_empty());
+}
+
+#[test]
+fn summaries_skip_non_finite_samples() {
+    let summary = BoxSummary::new(&[f64::NAN, 3.0, f64::INFINITY, 1.0, 2.0], Whiskers::Tukey);
+
+    assert_eq!(
+        summary.map(|summary| (summary.count, summary.median)),
+        Some((3, 2.0))
+    );
+    assert!(BoxSummary::new(&[f64::NAN], Whiskers::Tukey).is_none());
+}
+
+#[test]
+fn samples_without_an_exact_f32_value_are_summarized() {
+    for sample in [0.1, 0.7] {
+        let summary = BoxSummary::new(&[sample], Whiskers::Tukey).unwrap();
+
+        assert_eq!(summary.lower_whisker, sample);
+        assert_eq!(summary.median, sample);
+        assert_eq!(summary.upper_whisker, sample);
+        assert!(summary.outliers.is_empty());
+    }
+}
+
+#[test]
+fn whiskers_of_a_box_without_spread_stay_at_the_box() {
+    let samples = [0.3, 0.7, 0.7, 0.7, 0.7, 0.7, 0.9];
+    let summary = BoxSummary::new(&samples, Whiskers::Tukey).unwrap();
+
+    assert_eq!(summary.lower_quartile, 0.7);
+    assert_eq!(summary.upper_quartile, 0.7);
+    assert_eq!(summary.lower_whisker, 0.7);
+    assert_eq!(summary.upper_whisker, 0.7);
+    assert_eq!(summary.outliers, vec![0.3, 0.9]);
+
+    let series = violin_series([("a", vec![0.1]), ("b", vec![0.7]), ("c", samples.to_vec())]);
+
+    assert_eq!(series.summary("c").unwrap(), summary);
+    assert!(series.density("a").is_some());
+}
+
+#[test]
+fn repeated_categories_are_merged() {
+    let series = boxplot_series([("api", vec![1, 2]), ("db", vec![10]), ("api", vec![3])]);
+
+    assert_eq!(series.data.len(), 2);
+    assert_eq!(series.summary("api").unwrap().median, 2.0);
+    assert_eq!(series.summary("db").unwrap().count, 1);
+    assert!(series.summary("cache").is_none());
+}
+
+#[test]
+fn violin_density_is_normalized() {
+    let samples: Vec<f64> = (0..200).map(|i| (i as f64 * 0.37).sin() * 5.0).collect();
+    let series = violin_series([("api", samples.clone())]).bandwidth(0.5);
+    let density = series.density("api").unwrap();
+
+    let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    assert_eq!(density.first().unwrap().0, min);
+    assert_close(density.last().unwrap().0, max, 1e-12);
+
+    // The estimate spreads a little beyond the samples
+    let step = density[1].0 - density[0].0;
+    let area: f64 = density.iter().map(|(_, d)| d * step).sum();
+
+    assert!(area > 0.85 && area < 1.0, "{area}");
+}
+
+#[test]
+fn violin_density_of_a_symmetric_sample_is_symmetric() {
+    let series = violin_series([("api", [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0])]);
+    let density = series.density("api").unwrap();
+
+    for (low, high) in density.iter().zip(density.iter().rev()) {
+        assert_close(low.1, high.1, 1e-12);
+    }
+
+    let peak = density.iter().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+
+    assert_close(peak.0, 3.0, 0.05);
+    assert_eq!(series.summary("api").unwrap().median, 3.0);
+}
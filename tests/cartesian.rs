@@ -0,0 +1,26 @@
+use pliced::Cartesian;
+
+use plotters::prelude::Cartesian2d;
+
+#[test]
+fn cartesians_from_linear_coordinates() {
+    // y grows upwards, while pixels grow downwards
+    let cartesian = Cartesian::new(Cartesian2d::new(
+        0f32..10f32,
+        100f32..0f32,
+        (0..100, 0..100),
+    ));
+
+    assert_eq!(
+        cartesian.get_values(iced::Point::new(50.0, 25.0)),
+        Some((5.0, 75.0))
+    );
+    assert_eq!(
+        cartesian.get_coords(iced::Point::new(0.0, 100.0)),
+        Some(iced::Point::new(0.0, 0.0))
+    );
+    assert_eq!(
+        cartesian.get_y_values(iced::Point::new(10.0, 50.0)),
+        Some((50.0, None))
+    );
+}
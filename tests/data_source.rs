@@ -0,0 +1,215 @@
+use pliced::{Data, DataSource, Downsample, Extent, Scale};
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// The samples of `y = x % 10` at every integer x, computed on demand.
+struct Sawtooth {
+    len: usize,
+}
+
+impl DataSource for Sawtooth {
+    fn bounds(&self) -> (Extent, Extent) {
+        (
+            Extent::EMPTY.include(0.0..(self.len - 1) as f64),
+            Extent::EMPTY.include(0.0..9.0),
+        )
+    }
+
+    fn points(&self, x_range: Range<f64>) -> Cow<'_, [(f64, f64)]> {
+        let start = (x_range.start.floor() - 1.0).max(0.0) as usize;
+        let end = ((x_range.end.ceil() + 2.0).max(0.0) as usize).min(self.len);
+
+        (start..end.max(start))
+            .map(|x| (x as f64, (x % 10) as f64))
+            .collect()
+    }
+}
+
+fn ramp(len: usize) -> Data {
+    Data::new((0..len).map(|x| (x as f64, x as f64 * 2.0)))
+}
+
+#[test]
+fn bounds_of_in_memory_data() {
+    let data = Data::new([(1.0, -4.0), (2.0, f64::NAN), (3.0, 8.0), (5.0, 0.5)]);
+    let (x, y) = data.bounds();
+
+    assert_eq!(x.range(&Scale::Linear), Some(1.0..5.0));
+    assert_eq!(y.range(&Scale::Linear), Some(-4.0..8.0));
+    assert_eq!(y.range(&Scale::LOG10), Some(0.5..8.0));
+}
+
+#[test]
+fn bounds_of_empty_data() {
+    let (x, y) = Data::default().bounds();
+
+    assert_eq!(x.range(&Scale::Linear), None);
+    assert_eq!(y.range(&Scale::Linear), None);
+}
+
+#[test]
+fn points_are_cropped_with_neighbours() {
+    let data = ramp(100);
+
+    assert_eq!(data.points(10.5..12.5).as_ref(), &data[10..14]);
+    assert_eq!(data.points(10.0..12.0).as_ref(), &data[9..14]);
+    assert_eq!(data.points(-10.0..0.0).as_ref(), &data[0..2]);
+    assert_eq!(data.points(98.5..200.0).as_ref(), &data[98..100]);
+    assert!(data.points(200.0..300.0).is_empty());
+    assert!(data.points(-20.0..-10.0).is_empty());
+}
+
+#[test]
+fn points_between_two_samples() {
+    let data = Data::new([(0.0, 0.0), (10.0, 1.0), (20.0, 2.0)]);
+
+    assert_eq!(data.points(4.0..6.0).as_ref(), &data[0..2]);
+}
+
+#[test]
+fn points_of_unsorted_data() {
+    let data = Data::new([
+        (0.0, 0.0),
+        (10.0, 1.0),
+        (5.0, 2.0),
+        (20.0, 3.0),
+        (30.0, 4.0),
+    ]);
+
+    assert_eq!(data.points(4.0..6.0).as_ref(), &data[0..4]);
+    assert_eq!(data.points(25.0..26.0).as_ref(), &data[3..5]);
+}
+
+#[test]
+fn points_after_the_data_became_unsorted() {
+    let mut data = ramp(5);
+
+    assert_eq!(data.points(2.5..2.6).as_ref(), &data[2..4]);
+
+    data.update(|points| points[1].0 = 10.0);
+
+    // The line out to x = 10 and back crosses the range twice
+    assert_eq!(data.points(2.5..2.6).as_ref(), &data[0..4]);
+
+    data.update(|points| points[1].0 = 1.0);
+
+    assert_eq!(data.points(2.5..2.6).as_ref(), &data[2..4]);
+}
+
+#[test]
+fn points_are_borrowed_from_memory() {
+    let data = ramp(10);
+
+    assert!(matches!(data.points(2.0..4.0), Cow::Borrowed(_)));
+}
+
+#[test]
+fn downsampled_range_query() {
+    let data = ramp(100_000);
+    let reduced = data.downsampled(1000.0..51_000.0, 500, Downsample::MinMax);
+
+    assert!(reduced.len() <= 2000);
+    assert_eq!(reduced.first(), Some(&(999.0, 1998.0)));
+    assert_eq!(reduced.last(), Some(&(51_001.0, 102_002.0)));
+}
+
+#[test]
+fn small_ranges_are_not_downsampled() {
+    let data = ramp(100_000);
+
+    assert_eq!(
+        data.downsampled(0.0..100.0, 500, Downsample::MinMax).len(),
+        102
+    );
+    assert_eq!(
+        data.downsampled(0.0..5000.0, 500, Downsample::Disabled)
+            .len(),
+        5002
+    );
+}
+
+#[test]
+fn downsampling_keeps_breaks() {
+    let mut data = ramp(10_000);
+    data.update(|points| points[5000].1 = f64::NAN);
+
+    let reduced = data.downsampled(f64::NEG_INFINITY..f64::INFINITY, 100, Downsample::Lttb);
+
+    // Each half of the line gets half of the 200 points
+    assert_eq!(reduced.len(), 201);
+    assert_eq!(reduced.iter().filter(|point| point.1.is_nan()).count(), 1);
+    assert_eq!(reduced[99], (4999.0, 9998.0));
+    assert_eq!(reduced[101], (5001.0, 10_002.0));
+}
+
+#[test]
+fn broken_lines_share_the_columns() {
+    let mut data = Data::new((0..100_000).map(|x| (x as f64, (x % 7) as f64)));
+    data.update(|points| {
+        for x in (1000..100_000).step_by(1000) {
+            points[x].1 = f64::NAN;
+        }
+    });
+
+    let reduced = data.downsampled(0.0..100_000.0, 500, Downsample::MinMax);
+
+    assert!(reduced.len() <= 2000 + 99, "{}", reduced.len());
+    assert_eq!(reduced.iter().filter(|point| point.1.is_nan()).count(), 99);
+
+    // A line covering a tenth of the view gets a tenth of the columns
+    let data = Data::new((0..10_000).map(|x| (x as f64, (x % 7) as f64)));
+    let reduced = data.downsampled(0.0..100_000.0, 500, Downsample::MinMax);
+
+    assert!(reduced.len() <= 200, "{}", reduced.len());
+}
+
+#[test]
+fn versions_change_with_the_data() {
+    let mut data = ramp(10);
+    let shared = data.clone();
+    let version = data.version();
+
+    assert_ne!(version, 0);
+    assert_eq!(shared.version(), version);
+
+    data.push((10.0, 20.0));
+
+    assert_ne!(data.version(), version);
+    assert_eq!(data.len(), 11);
+    assert_eq!(shared.len(), 10);
+    assert_eq!(data.bounds().0.range(&Scale::Linear), Some(0.0..10.0));
+    assert_eq!(shared.bounds().0.range(&Scale::Linear), Some(0.0..9.0));
+}
+
+#[test]
+fn custom_sources_are_downsampled_by_default() {
+    let source = Sawtooth { len: 1_000_000 };
+    let points = source.points(100.0..200.0);
+
+    assert_eq!(points.first(), Some(&(99.0, 9.0)));
+    assert_eq!(points.last(), Some(&(201.0, 1.0)));
+
+    let reduced = source.downsampled(0.0..1_000_000.0, 1000, Downsample::MinMax);
+
+    assert!(reduced.len() <= 4000);
+    assert!(reduced.contains(&(9.0, 9.0)));
+    assert!(reduced.contains(&(999_009.0, 9.0)));
+    assert_eq!(source.version(), 0);
+}
+
+#[test]
+fn indexed_points_start_at_the_first_visible_point() {
+    let data = ramp(100);
+    let (first, points) = data.indexed_points(10.0..20.0);
+
+    assert_eq!(first, 9);
+    assert_eq!(points.first(), Some(&(9.0, 18.0)));
+    assert_eq!(points.len(), 13);
+
+    let source = Sawtooth { len: 100 };
+    let (first, points) = source.indexed_points(10.0..20.0);
+
+    assert_eq!(first, 0);
+    assert_eq!(points.len(), 100);
+}
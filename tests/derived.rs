@@ -0,0 +1,151 @@
+use pliced::{Axis, Derivation, Fit, LineSeries, Regression, line_series, point_series};
+
+fn assert_close(a: f64, b: f64, tolerance: f64) {
+    assert!((a - b).abs() <= tolerance, "{a} != {b}");
+}
+
+fn points(series: &pliced::DerivedSeries) -> Vec<(f64, f64)> {
+    series.data.to_vec()
+}
+
+#[test]
+fn moving_average_restarts_after_breaks() {
+    let series = line_series(
+        [1.0, 2.0, 3.0, 4.0, f64::NAN, 6.0, 7.0, 8.0, 9.0]
+            .into_iter()
+            .enumerate()
+            .map(|(x, y)| (x as f64, y)),
+    );
+    let average = points(&series.derive(Derivation::MovingAverage(3)));
+
+    assert_eq!(&average[..2], &[(2.0, 2.0), (3.0, 3.0)]);
+    assert!(average[2].1.is_nan());
+    assert_eq!(&average[3..], &[(7.0, 7.0), (8.0, 8.0)]);
+}
+
+#[test]
+fn exponential_moving_average() {
+    let series = point_series([(0.0, 2.0), (1.0, 4.0), (2.0, 8.0)]);
+    let average = points(&series.derive(Derivation::ExponentialMovingAverage(0.5)));
+
+    assert_eq!(average, vec![(0.0, 2.0), (1.0, 3.0), (2.0, 5.5)]);
+}
+
+#[test]
+fn cumulative_sum_skips_breaks() {
+    let series = line_series([(0.0, 1.0), (1.0, 2.0), (2.0, f64::NAN), (3.0, 3.0)]);
+    let sum = points(&series.derive(Derivation::CumulativeSum));
+
+    assert_eq!(sum, vec![(0.0, 1.0), (1.0, 3.0), (3.0, 6.0)]);
+}
+
+#[test]
+fn linear_regression() {
+    let series = point_series([(0, 1), (1, 3), (2, 2), (3, 5), (4, 4)]);
+    let derived = series.derive(Derivation::Regression(Regression::Linear));
+    let fit = derived.fit.clone().unwrap();
+
+    assert_close(fit.coefficients[0], 1.4, 1e-12);
+    assert_close(fit.coefficients[1], 0.8, 1e-12);
+    assert_close(fit.r_squared, 0.64, 1e-12);
+    assert_close(fit.eval(10.0), 9.4, 1e-12);
+
+    let line = points(&derived);
+
+    assert_eq!(line.first(), Some(&(0.0, 1.4)));
+    assert_close(line.last().unwrap().1, 4.6, 1e-12);
+    assert!(derived.band().is_empty());
+}
+
+#[test]
+fn confidence_band_of_a_linear_regression() {
+    let series = point_series([(0, 1), (1, 3), (2, 2), (3, 5), (4, 4)]);
+    let derived = series
+        .derive(Derivation::Regression(Regression::Linear))
+        .confidence(0.95);
+    let band = derived.band();
+
+    // The standard error at x = 0 is sqrt(1.2 * (1 / 5 + 4 / 10)), with t = 3.182
+    let (x, lower, upper) = band[0];
+
+    assert_eq!(x, 0.0);
+    assert_close((upper - lower) / 2.0, 3.182 * 0.72f64.sqrt(), 0.01);
+    assert_close((upper + lower) / 2.0, 1.4, 1e-12);
+
+    // The band is narrowest at the mean of x
+    let widths: Vec<f64> = band.iter().map(|(_, lower, upper)| upper - lower).collect();
+    let narrowest = widths
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(b.1))
+        .unwrap()
+        .0;
+
+    assert_close(band[narrowest].0, 2.0, 0.02);
+    assert!(widths[0] > widths[narrowest]);
+}
+
+#[test]
+fn polynomial_regression() {
+    let series = line_series(
+        (10..=30)
+            .map(f64::from)
+            .map(|x| (x, 2.0 - 3.0 * x + 0.5 * x * x)),
+    );
+    let fit = series
+        .derive(Derivation::Regression(Regression::Polynomial(2)))
+        .fit
+        .unwrap();
+
+    assert_eq!(fit.coefficients.len(), 3);
+    assert_close(fit.coefficients[0], 2.0, 1e-6);
+    assert_close(fit.coefficients[1], -3.0, 1e-7);
+    assert_close(fit.coefficients[2], 0.5, 1e-9);
+    assert_close(fit.r_squared, 1.0, 1e-12);
+}
+
+#[test]
+fn regressions_need_enough_points() {
+    let series = point_series([(0.0, 1.0), (1.0, 2.0), (2.0, f64::NAN), (3.0, 0.0)]);
+    let derived = series.derive(Derivation::Regression(Regression::Polynomial(3)));
+
+    assert!(derived.fit.is_none());
+    assert!(derived.data.is_empty());
+}
+
+#[test]
+fn loess_follows_a_line() {
+    let series = point_series((0..50).map(f64::from).map(|x| (x, 3.0 * x + 1.0)));
+    let derived = series
+        .derive(Derivation::Regression(Regression::Loess(0.3)))
+        .confidence(0.95);
+
+    assert!(derived.fit.is_none());
+    assert_eq!(derived.data.len(), 200);
+
+    for ((x, y), (_, lower, upper)) in derived.data.iter().zip(derived.band()) {
+        assert_close(*y, 3.0 * x + 1.0, 1e-9);
+        assert_close(lower, *y, 1e-6);
+        assert_close(upper, *y, 1e-6);
+    }
+}
+
+#[test]
+fn derived_series_keep_the_axis() {
+    let series = LineSeries::new([(0.0, 1.0), (1.0, 2.0)]).axis(Axis::Secondary);
+
+    assert_eq!(
+        series.derive(Derivation::CumulativeSum).axis,
+        Axis::Secondary
+    );
+}
+
+#[test]
+fn fits_evaluate_their_polynomial() {
+    let fit = Fit {
+        coefficients: vec![1.0, -2.0, 3.0],
+        r_squared: 1.0,
+    };
+
+    assert_eq!(fit.eval(2.0), 9.0);
+}
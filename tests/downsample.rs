@@ -0,0 +1,130 @@
+use pliced::downsample::{lttb, min_max};
+
+const ZIGZAG: [f64; 20] = [
+    3.0, 5.0, 1.0, 8.0, 2.0, 9.0, 4.0, 7.0, 0.0, 6.0, 5.0, 3.0, 8.0, 1.0, 2.0, 9.0, 4.0, 6.0, 7.0,
+    0.0,
+];
+
+fn indexed(values: &[f64]) -> Vec<(f64, f64)> {
+    values
+        .iter()
+        .enumerate()
+        .map(|(x, y)| (x as f64, *y))
+        .collect()
+}
+
+#[test]
+fn lttb_matches_reference() {
+    let data = indexed(&ZIGZAG);
+
+    assert_eq!(
+        lttb(&data, 7),
+        [
+            (0.0, 3.0),
+            (3.0, 8.0),
+            (4.0, 2.0),
+            (9.0, 6.0),
+            (13.0, 1.0),
+            (15.0, 9.0),
+            (19.0, 0.0)
+        ]
+    );
+    assert_eq!(
+        lttb(&data, 5),
+        [(0.0, 3.0), (5.0, 9.0), (8.0, 0.0), (15.0, 9.0), (19.0, 0.0)]
+    );
+}
+
+#[test]
+fn lttb_keeps_spikes() {
+    let data = indexed(&[0.0, 1.0, 0.0, 0.0, 10.0, 0.0, 0.0, -4.0, 0.0, 1.0, 0.0, 0.0]);
+
+    assert_eq!(
+        lttb(&data, 4),
+        [(0.0, 0.0), (4.0, 10.0), (7.0, -4.0), (11.0, 0.0)]
+    );
+}
+
+#[test]
+fn lttb_returns_small_data_unchanged() {
+    let data = indexed(&ZIGZAG);
+
+    assert_eq!(lttb(&data, 20), data);
+    assert_eq!(lttb(&data, 100), data);
+    assert_eq!(lttb(&data, 2), data);
+    assert_eq!(lttb(&[], 10), []);
+}
+
+#[test]
+fn lttb_reduces_to_threshold() {
+    let data: Vec<_> = (0..100_000)
+        .map(|x| x as f64)
+        .map(|x| (x, (x / 1000.0).sin()))
+        .collect();
+    let sampled = lttb(&data, 2000);
+
+    assert_eq!(sampled.len(), 2000);
+    assert_eq!(sampled.first(), data.first());
+    assert_eq!(sampled.last(), data.last());
+    assert!(sampled.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn min_max_matches_reference() {
+    let data = indexed(&ZIGZAG);
+
+    assert_eq!(
+        min_max(&data, 0.0..20.0, 4),
+        [
+            (0.0, 3.0),
+            (2.0, 1.0),
+            (3.0, 8.0),
+            (4.0, 2.0),
+            (5.0, 9.0),
+            (8.0, 0.0),
+            (9.0, 6.0),
+            (10.0, 5.0),
+            (12.0, 8.0),
+            (13.0, 1.0),
+            (14.0, 2.0),
+            (15.0, 9.0),
+            (19.0, 0.0)
+        ]
+    );
+}
+
+#[test]
+fn min_max_keeps_extremes_of_every_bucket() {
+    let mut data: Vec<_> = (0..1_000_000)
+        .map(|x| x as f64)
+        .map(|x| (x, (x / 5000.0).sin()))
+        .collect();
+    data[123_456].1 = 50.0;
+    data[765_432].1 = -50.0;
+
+    let sampled = min_max(&data, 0.0..1_000_000.0, 1000);
+
+    assert!(sampled.len() <= 4000);
+    assert!(sampled.contains(&data[123_456]));
+    assert!(sampled.contains(&data[765_432]));
+    assert_eq!(sampled.first(), data.first());
+    assert_eq!(sampled.last(), data.last());
+}
+
+#[test]
+fn min_max_clamps_points_outside_of_range() {
+    let data = indexed(&ZIGZAG);
+
+    assert_eq!(
+        min_max(&data, 5.0..15.0, 1),
+        [(0.0, 3.0), (5.0, 9.0), (8.0, 0.0), (19.0, 0.0)]
+    );
+}
+
+#[test]
+fn min_max_returns_data_for_empty_range() {
+    let data = indexed(&ZIGZAG);
+
+    assert_eq!(min_max(&data, 5.0..5.0, 10), data);
+    assert_eq!(min_max(&data, 0.0..20.0, 0), data);
+}
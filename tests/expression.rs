@@ -0,0 +1,266 @@
+use pliced::expression::{ErrorKind, Expression, ParseError, Variable};
+use pliced::{DataSource, Function};
+
+use std::f64::consts::{E, PI};
+
+fn eval(input: &str) -> f64 {
+    eval_at(input, 0.0, 0.0)
+}
+
+fn eval_at(input: &str, x: f64, t: f64) -> f64 {
+    Expression::parse(input)
+        .unwrap_or_else(|error| panic!("{input}: {error}"))
+        .eval(x, t)
+}
+
+fn error(input: &str) -> ParseError {
+    Expression::parse(input).expect_err(input)
+}
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-12, "{a} != {b}");
+}
+
+#[test]
+fn precedence_and_associativity() {
+    assert_eq!(eval("1 + 2 * 3"), 7.0);
+    assert_eq!(eval("(1 + 2) * 3"), 9.0);
+    assert_eq!(eval("1 - 2 - 3"), -4.0);
+    assert_eq!(eval("8 / 2 / 2"), 2.0);
+    assert_eq!(eval("10 / 4"), 2.5);
+    assert_eq!(eval("7 % 4 * 2"), 6.0);
+    assert_eq!(eval("2^3^2"), 512.0);
+    assert_eq!(eval("2 * 3^2"), 18.0);
+}
+
+#[test]
+fn unary_signs() {
+    assert_eq!(eval("-2^2"), -4.0);
+    assert_eq!(eval("(-2)^2"), 4.0);
+    assert_eq!(eval("2^-1"), 0.5);
+    assert_eq!(eval("--3"), 3.0);
+    assert_eq!(eval("+3 - -2"), 5.0);
+    assert_eq!(eval("2 * -3"), -6.0);
+}
+
+#[test]
+fn numbers() {
+    assert_eq!(eval("42"), 42.0);
+    assert_eq!(eval(".5"), 0.5);
+    assert_eq!(eval("1.5e3"), 1500.0);
+    assert_eq!(eval("1E+2"), 100.0);
+    assert_eq!(eval("25e-2"), 0.25);
+    // Without digits, the e is the constant
+    assert_close(eval("2e"), 2.0 * E);
+}
+
+#[test]
+fn implicit_multiplication() {
+    assert_eq!(eval_at("2x", 3.0, 0.0), 6.0);
+    assert_eq!(eval_at("3t", 0.0, 2.0), 6.0);
+    assert_eq!(eval_at("(x + 1)(x - 1)", 3.0, 0.0), 8.0);
+    assert_eq!(eval_at("x(x + 1)", 3.0, 0.0), 12.0);
+    assert_eq!(eval_at("2x^2", 3.0, 0.0), 18.0);
+    assert_eq!(eval_at("-x^2", 3.0, 0.0), -9.0);
+    assert_eq!(eval_at("x t", 3.0, 2.0), 6.0);
+    assert_close(eval("2pi"), 2.0 * PI);
+    assert_close(eval("2 sin(pi / 2)"), 2.0);
+    assert_close(eval("sin(pi / 2)cos(0)"), 1.0);
+    assert_eq!(eval("6 / 2(1 + 2)"), 9.0);
+}
+
+#[test]
+fn functions() {
+    assert_close(eval("sin(pi / 2)"), 1.0);
+    assert_close(eval("cos(pi)"), -1.0);
+    assert_close(eval("tan(pi / 4)"), 1.0);
+    assert_close(eval("atan2(1, 1)"), PI / 4.0);
+    assert_close(eval("asin(1) + acos(1) + atan(0)"), PI / 2.0);
+    assert_close(eval("cosh(0) + sinh(0) + tanh(0)"), 1.0);
+    assert_eq!(eval("sqrt(16) + cbrt(27)"), 7.0);
+    assert_close(eval("ln(e) + exp(0)"), 2.0);
+    assert_close(eval("log(1000) + log2(8)"), 6.0);
+    assert_eq!(eval("abs(-3) + floor(2.7) + ceil(2.2) + round(2.5)"), 11.0);
+    assert_eq!(eval("sign(-2) + sign(0) + sign(5)"), 0.0);
+    assert_eq!(eval("pow(2, 10) + hypot(3, 4)"), 1029.0);
+    assert_eq!(eval("max(1, 5, 3) + min(4)"), 9.0);
+    assert_eq!(eval("sin (0)"), 0.0);
+}
+
+#[test]
+fn constants() {
+    assert_eq!(eval("pi"), PI);
+    assert_eq!(eval("π"), PI);
+    assert_eq!(eval("tau"), 2.0 * PI);
+    assert_eq!(eval("e"), E);
+    assert_eq!(eval("-inf"), f64::NEG_INFINITY);
+}
+
+#[test]
+fn values_which_are_not_finite() {
+    assert!(eval("sqrt(-1)").is_nan());
+    assert!(eval("ln(0)").is_infinite());
+    assert_eq!(eval_at("1 / x", 0.0, 0.0), f64::INFINITY);
+}
+
+#[test]
+fn variables() {
+    let expression = Expression::parse("x^2 + t").unwrap();
+
+    assert_eq!(expression.eval(3.0, 1.0), 10.0);
+    assert_eq!(expression.eval(-3.0, 0.5), 9.5);
+    assert!(expression.uses(Variable::X));
+    assert!(expression.uses(Variable::T));
+
+    let expression: Expression = "sin(x) / x".parse().unwrap();
+
+    assert!(expression.uses(Variable::X));
+    assert!(!expression.uses(Variable::T));
+    assert!(!Expression::parse("pi").unwrap().uses(Variable::X));
+}
+
+#[test]
+fn errors_at_the_end_of_the_input() {
+    assert_eq!(
+        error(""),
+        ParseError {
+            kind: ErrorKind::UnexpectedEnd,
+            position: 0..0
+        }
+    );
+    assert_eq!(
+        error("1 +"),
+        ParseError {
+            kind: ErrorKind::UnexpectedEnd,
+            position: 3..3
+        }
+    );
+    assert_eq!(
+        error("sin(x"),
+        ParseError {
+            kind: ErrorKind::UnclosedParenthesis,
+            position: 3..4
+        }
+    );
+    assert_eq!(
+        error("2 * (x + 1"),
+        ParseError {
+            kind: ErrorKind::UnclosedParenthesis,
+            position: 4..5
+        }
+    );
+}
+
+#[test]
+fn errors_at_tokens() {
+    assert_eq!(
+        error("2 $ 3"),
+        ParseError {
+            kind: ErrorKind::UnexpectedCharacter('$'),
+            position: 2..3
+        }
+    );
+    assert_eq!(
+        error("1 2"),
+        ParseError {
+            kind: ErrorKind::UnexpectedToken,
+            position: 2..3
+        }
+    );
+    assert_eq!(
+        error("(1))"),
+        ParseError {
+            kind: ErrorKind::UnexpectedToken,
+            position: 3..4
+        }
+    );
+    assert_eq!(
+        error("2 * )"),
+        ParseError {
+            kind: ErrorKind::UnexpectedToken,
+            position: 4..5
+        }
+    );
+    assert_eq!(
+        error("max(1 2)"),
+        ParseError {
+            kind: ErrorKind::UnexpectedToken,
+            position: 6..7
+        }
+    );
+    assert_eq!(
+        error("1.2.3"),
+        ParseError {
+            kind: ErrorKind::InvalidNumber,
+            position: 0..5
+        }
+    );
+}
+
+#[test]
+fn errors_at_names() {
+    assert_eq!(
+        error("y + 1"),
+        ParseError {
+            kind: ErrorKind::UnknownVariable("y".to_string()),
+            position: 0..1
+        }
+    );
+    assert_eq!(
+        error("2 * foo(x)"),
+        ParseError {
+            kind: ErrorKind::UnknownFunction("foo".to_string()),
+            position: 4..7
+        }
+    );
+    assert_eq!(
+        error("1 + atan2(1)"),
+        ParseError {
+            kind: ErrorKind::Arguments {
+                function: "atan2".to_string(),
+                expected: 2,
+                found: 1
+            },
+            position: 4..12
+        }
+    );
+    assert_eq!(
+        error("min()"),
+        ParseError {
+            kind: ErrorKind::Arguments {
+                function: "min".to_string(),
+                expected: 1,
+                found: 0
+            },
+            position: 0..5
+        }
+    );
+    // Positions are byte offsets
+    assert_eq!(
+        error("π + ä"),
+        ParseError {
+            kind: ErrorKind::UnknownVariable("ä".to_string()),
+            position: 5..7
+        }
+    );
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(error("foo(x)").to_string(), "unknown function 'foo' at 0");
+    assert_eq!(
+        error("sin(1, 2)").to_string(),
+        "sin expects 1 argument, found 2 at 0"
+    );
+    assert_eq!(error("2 +").to_string(), "unexpected end of input at 3");
+}
+
+#[test]
+fn expressions_are_plotted_as_functions() {
+    let function = Function::from(Expression::parse("x^2 - 1").unwrap());
+    let points = function.points(-2.0..2.0);
+
+    assert_eq!(points.first(), Some(&(-2.0, 3.0)));
+    assert_eq!(points.last(), Some(&(2.0, 3.0)));
+    assert!(points.iter().all(|(x, y)| (x * x - 1.0 - y).abs() < 1e-12));
+}
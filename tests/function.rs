@@ -0,0 +1,160 @@
+use pliced::{DataSource, Downsample, Function, Implicit, Parametric, Scale};
+
+use std::ops::Range;
+
+fn sample(source: &impl DataSource, x_range: Range<f64>) -> Vec<(f64, f64)> {
+    source
+        .downsampled(x_range, 400, Downsample::default())
+        .into_owned()
+}
+
+fn is_break(point: &(f64, f64)) -> bool {
+    point.0.is_nan() || point.1.is_nan()
+}
+
+/// Returns the neighbouring points on either side of every break.
+fn breaks(points: &[(f64, f64)]) -> Vec<((f64, f64), (f64, f64))> {
+    points
+        .split(is_break)
+        .collect::<Vec<_>>()
+        .windows(2)
+        .filter_map(|parts| Some((*parts[0].last()?, *parts[1].first()?)))
+        .collect()
+}
+
+#[test]
+fn steps_break_the_line() {
+    let points = sample(&Function::new(f64::floor), 0.0..2.5);
+    let breaks = breaks(&points);
+
+    assert_eq!(breaks.len(), 2, "{breaks:?}");
+
+    for ((before, after), step) in breaks.into_iter().zip([1.0, 2.0]) {
+        assert!(before.0 < step && step - before.0 < 0.01, "{before:?}");
+        assert!(after.0 >= step && after.0 - step < 0.01, "{after:?}");
+        assert_eq!(after.1 - before.1, 1.0);
+    }
+}
+
+#[test]
+fn asymptotes_break_the_line() {
+    let points = sample(&Function::new(|x| 1.0 / x), -1.0..1.0);
+
+    assert_eq!(breaks(&points).len(), 1);
+
+    // No part of the line crosses the asymptote
+    for part in points.split(is_break) {
+        assert!(part.iter().all(|(x, _)| *x < 0.0) || part.iter().all(|(x, _)| *x > 0.0));
+    }
+}
+
+#[test]
+fn steep_continuous_curves_are_not_broken() {
+    let points = sample(&Function::new(|x| (20.0 * x).tanh()), -1.0..1.0);
+
+    assert!(breaks(&points).is_empty());
+    assert!(!points.iter().any(is_break));
+}
+
+#[test]
+fn curves_are_refined_where_they_bend() {
+    let count = |points: &[(f64, f64)], range: Range<f64>| {
+        points.iter().filter(|(x, _)| range.contains(x)).count()
+    };
+
+    // A line keeps its evenly spaced samples
+    let line = sample(&Function::new(|x| 2.0 * x + 1.0), -1.0..1.0);
+
+    assert_eq!(line.len(), 101);
+
+    // The bend of tanh is around 0, while it is flat at 0.5
+    let curve = sample(&Function::new(|x| (20.0 * x).tanh()), -1.0..1.0);
+
+    assert!(count(&curve, -0.1..0.1) > 2 * count(&line, -0.1..0.1));
+    assert_eq!(count(&curve, 0.5..0.7), count(&line, 0.5..0.7));
+}
+
+#[test]
+fn functions_are_sampled_within_their_domain() {
+    let points = sample(&Function::new(f64::sqrt).domain(0.0..4.0), -10.0..2.0);
+
+    assert_eq!(points.first(), Some(&(0.0, 0.0)));
+    assert_eq!(points.last().map(|point| point.0), Some(2.0));
+    assert!(sample(&Function::new(f64::sqrt).domain(0.0..4.0), 5.0..6.0).is_empty());
+}
+
+#[test]
+fn functions_without_a_domain_fit_y_to_the_visible_range() {
+    let square = Function::new(|x| x * x);
+
+    assert_eq!(square.bounds().0.range(&Scale::Linear), None);
+    let range = square.y_bounds(-2.0..3.0).range(&Scale::Linear).unwrap();
+
+    // The samples miss the minimum at 0 by less than a step
+    assert!(range.start >= 0.0 && range.start < 1e-3, "{range:?}");
+    assert_eq!(range.end, 9.0);
+
+    let bounded = Function::new(|x| x * x).domain(0.0..1.0);
+
+    assert_eq!(
+        bounded.y_bounds(-2.0..3.0).range(&Scale::Linear),
+        Some(0.0..1.0)
+    );
+}
+
+#[test]
+fn implicit_circles_are_closed() {
+    // The grid misses the circle's extremes, which would be shared by all cells around them
+    let circle = Implicit::new(|x, y| x * x + y * y - 1.0, -1.9..2.1, -1.9..2.1);
+    let points = sample(&circle, -1.9..2.1);
+    let segments: Vec<&[(f64, f64)]> = points
+        .split(is_break)
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    assert!(segments.len() > 50);
+
+    let mut ends: Vec<(f64, f64)> = Vec::new();
+
+    for segment in &segments {
+        assert_eq!(segment.len(), 2);
+
+        for (x, y) in segment.iter() {
+            assert!((x.hypot(*y) - 1.0).abs() < 0.01, "{x} {y}");
+        }
+
+        ends.extend(segment.iter());
+    }
+
+    // Every end is shared by exactly two segments, so the contour has no loose ends
+    for end in &ends {
+        let shared = ends
+            .iter()
+            .filter(|other| (other.0 - end.0).abs() < 1e-9 && (other.1 - end.1).abs() < 1e-9)
+            .count();
+
+        assert_eq!(shared, 2, "{end:?}");
+    }
+}
+
+#[test]
+fn parametric_curves_are_cropped_to_the_visible_range() {
+    let circle = Parametric::new(|t: f64| (t.cos(), t.sin()), 0.0..std::f64::consts::TAU);
+    let whole = sample(&circle, -2.0..2.0);
+    let cropped = sample(&circle, 0.5..2.0);
+
+    assert!(!whole.iter().any(is_break));
+    assert!(cropped.len() < whole.len() / 2);
+
+    // The arc right of x = 0.5 is split where t wraps around
+    let parts: Vec<_> = cropped
+        .split(is_break)
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    assert_eq!(parts.len(), 2);
+
+    for (x, _) in parts.concat() {
+        assert!(x > 0.45, "{x}");
+    }
+}
@@ -0,0 +1,22 @@
+use pliced::heatmap_series;
+
+#[test]
+fn color_range_covers_the_values() {
+    let heatmap = heatmap_series([0, 1, 2], [0, 1], [-3.0, 5.0]);
+
+    assert_eq!(heatmap.color_range(), -3.0..5.0);
+    assert_eq!(heatmap.value_range(0..10).color_range(), 0.0..10.0);
+}
+
+#[test]
+fn log_color_range_starts_at_the_smallest_positive_value() {
+    let heatmap = heatmap_series([0, 1, 2, 3], [0, 1], [-1.0, 0.01, 100.0]).log_scale(true);
+
+    assert_eq!(heatmap.color_range(), 0.01..100.0);
+
+    let heatmap = heatmap.value_range(0.0..1000.0);
+    let range = heatmap.color_range();
+
+    assert!((range.start - 0.01).abs() < 1e-12, "{range:?}");
+    assert!((range.end - 1000.0).abs() < 1e-9, "{range:?}");
+}
@@ -0,0 +1,39 @@
+use pliced::line_series;
+
+#[test]
+fn gaps_wider_than_max_gap_break_the_line() {
+    let points = [(0.0, 1.0), (1.0, 2.0), (2.0, 3.0), (10.0, 4.0), (11.0, 5.0)];
+    let series = line_series(points).max_gap(5);
+    let segments: Vec<_> = series.segments(&points).collect();
+
+    assert_eq!(segments, vec![&points[..3], &points[3..]]);
+
+    // Without a maximum gap, the line connects every point
+    assert_eq!(line_series(points).segments(&points).count(), 1);
+}
+
+#[test]
+fn missing_values_break_the_line() {
+    let values = [Some(1.0), Some(2.0), None, Some(4.0), Some(5.0)];
+    let series = line_series(values.into_iter().enumerate().map(|(x, y)| (x as f64, y)));
+    let points = series.source.points(f64::NEG_INFINITY..f64::INFINITY);
+    let segments: Vec<_> = series.segments(&points).collect();
+
+    assert!(points[2].1.is_nan());
+    assert_eq!(segments, vec![&points[..2], &points[3..]]);
+}
+
+#[test]
+fn gaps_are_measured_between_drawn_points() {
+    let points = [
+        (0.0, 1.0),
+        (1.0, f64::NAN),
+        (2.0, 3.0),
+        (3.0, 4.0),
+        (9.0, 5.0),
+    ];
+    let series = line_series(points).max_gap(2.0);
+    let segments: Vec<_> = series.segments(&points).collect();
+
+    assert_eq!(segments, vec![&points[..1], &points[2..4], &points[4..]]);
+}
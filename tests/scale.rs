@@ -0,0 +1,70 @@
+use pliced::{AxisOptions, Chart, Scale};
+
+#[test]
+fn logarithmic_scales_with_a_valid_base() {
+    assert_eq!(Scale::log(10.0), Scale::LOG10);
+    assert_eq!(Scale::log(0.5), Scale::Log(0.5));
+}
+
+#[test]
+#[should_panic(expected = "base of a logarithmic scale")]
+fn logarithmic_scales_with_base_one() {
+    Scale::log(1.0);
+}
+
+#[test]
+#[should_panic(expected = "base of a logarithmic scale")]
+fn logarithmic_scales_with_a_negative_base() {
+    Scale::log(-2.0);
+}
+
+#[test]
+#[should_panic(expected = "base of a logarithmic scale")]
+fn logarithmic_scales_with_a_base_which_is_not_finite() {
+    Scale::log(f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "base of a logarithmic scale")]
+fn charts_reject_an_invalid_base() {
+    let _ = Chart::<()>::new().y_scale(Scale::Log(0.0));
+}
+
+#[test]
+#[should_panic(expected = "threshold of a symmetric logarithmic scale")]
+fn charts_reject_an_invalid_symlog_threshold() {
+    let _ = Chart::<()>::new().x_scale(Scale::Symlog(0.0));
+}
+
+#[test]
+#[should_panic(expected = "threshold of a symmetric logarithmic scale")]
+fn charts_reject_a_symlog_threshold_which_is_not_finite() {
+    let _ = Chart::<()>::new().y_scale(Scale::Symlog(f64::NAN));
+}
+
+#[test]
+fn time_labels_match_a_custom_tick_count() {
+    let labels = AxisOptions::new()
+        .tick_count(50)
+        .tick_labels(0.0..10.0, Scale::Time);
+
+    assert_eq!(labels.len(), 51);
+    assert_eq!(labels[1], (0.2, "00:00.200".to_string()));
+
+    for pair in labels.windows(2) {
+        assert_ne!(pair[0].1, pair[1].1);
+    }
+}
+
+#[test]
+fn time_labels_match_a_tick_step() {
+    let labels = AxisOptions::new()
+        .tick_step(0.5)
+        .tick_labels(60.0..100.0, Scale::Time);
+
+    assert_eq!(labels[1], (60.5, "01:00.500".to_string()));
+
+    for pair in labels.windows(2) {
+        assert_ne!(pair[0].1, pair[1].1);
+    }
+}
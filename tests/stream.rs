@@ -0,0 +1,111 @@
+use pliced::{Extent, Scale, Series, StreamingSeries};
+
+use std::ops::Range;
+
+/// A deterministic noisy signal with a break every 17 samples.
+fn sample(x: usize) -> (f64, f64) {
+    let y = if x % 17 == 16 {
+        f64::NAN
+    } else {
+        ((x as f64 * 12.9898).sin() * 43758.545).fract() * 100.0
+    };
+
+    (x as f64, y)
+}
+
+/// Returns the y range of the kept samples within the window, scanning all of them.
+fn scanned_y_range(series: &StreamingSeries) -> Option<Range<f64>> {
+    let Range { start, end } = series.x_range();
+
+    series
+        .samples()
+        .iter()
+        .filter(|(x, y)| (start..=end).contains(x) && y.is_finite())
+        .fold(Extent::EMPTY, |extent, &(_, y)| extent.include(y..y))
+        .range(&Scale::Linear)
+}
+
+#[test]
+fn only_the_newest_samples_are_kept() {
+    let mut series = StreamingSeries::new(10, 100.0);
+    series.extend((0..25).map(sample));
+
+    let xs: Vec<f64> = series.samples().iter().map(|(x, _)| *x).collect();
+
+    assert_eq!(xs, (15..25).map(|x| x as f64).collect::<Vec<_>>());
+    assert_eq!(series.latest(), Some(sample(24)));
+}
+
+#[test]
+fn series_draw_only_the_kept_samples() {
+    let mut series = StreamingSeries::new(10, 100.0);
+
+    for x in 0..25 {
+        series.push(sample(x));
+
+        let Series::Line(line) = Series::from(&series) else {
+            panic!("a streaming series is drawn as a line");
+        };
+        let points = line.source.points(f64::NEG_INFINITY..f64::INFINITY);
+
+        assert_eq!(points.len(), series.samples().len());
+        assert_eq!(points.first(), series.samples().first());
+    }
+}
+
+#[test]
+fn y_range_follows_the_window() {
+    // The window holds more samples than a small capacity keeps, and fewer than a large one
+    for capacity in [4, 50] {
+        let mut series = StreamingSeries::new(capacity, 5.0);
+
+        for x in 0..200 {
+            series.push(sample(x));
+
+            assert_eq!(series.x_range(), x as f64 - 5.0..x as f64);
+            assert_eq!(series.y_range(), scanned_y_range(&series), "at {x}");
+        }
+    }
+}
+
+#[test]
+fn pausing_keeps_the_window() {
+    let mut series = StreamingSeries::new(50, 5.0);
+    series.extend((0..20).map(sample));
+    series.pause();
+    series.extend((20..30).map(sample));
+
+    assert!(!series.is_following());
+    assert_eq!(series.x_range(), 14.0..19.0);
+    assert_eq!(series.y_range(), scanned_y_range(&series));
+
+    series.follow();
+
+    assert_eq!(series.x_range(), 24.0..29.0);
+    assert_eq!(series.y_range(), scanned_y_range(&series));
+}
+
+#[test]
+fn panning_stops_and_resumes_following() {
+    let mut series = StreamingSeries::new(50, 5.0);
+    series.extend((0..80).map(sample));
+
+    series.pan(-10.0);
+
+    assert!(!series.is_following());
+    assert_eq!(series.x_range(), 64.0..69.0);
+    assert_eq!(series.y_range(), scanned_y_range(&series));
+
+    // The window stops at the oldest kept sample
+    series.pan(-1000.0);
+
+    assert_eq!(series.x_range(), 25.0..30.0);
+    assert_eq!(series.y_range(), scanned_y_range(&series));
+
+    series.extend((80..90).map(sample));
+    series.pan(1000.0);
+
+    assert!(series.is_following());
+    assert_eq!(series.x_range(), 84.0..89.0);
+    assert_eq!(series.y_range(), scanned_y_range(&series));
+}